@@ -1,15 +1,46 @@
-use crate::{data_item::DataItem, key_utils::CryptoDriver};
+use std::io::Read;
+use tokio::io::AsyncRead;
+use crate::{
+    data_item::DataItem,
+    deep_hash::{deep_hash, deep_hash_list_with_blob, deep_hash_stream, deep_hash_stream_async, DeepHashChunk},
+    errors::ArBundleErrors,
+    key_utils::CryptoDriver
+};
 
-pub fn get_signature_data(item: &mut DataItem) -> [u8; 48] {
-    let mut vec: Vec<u8> = vec![];
-    vec.append(CryptoDriver::string_to_buffer("dataitem").to_vec().as_mut());
-    vec.append(CryptoDriver::string_to_buffer("1").to_vec().as_mut());
-    vec.append(CryptoDriver::string_to_buffer(&item.base.signature_type.to_string()).to_vec().as_mut());
-    vec.append(item.base.raw_owner.as_mut());
-    vec.append(item.base.raw_target.as_mut());
-    vec.append(item.base.raw_anchor.as_mut());
-    vec.append(item.base.raw_tags.as_mut());
-    vec.append(item.base.raw_data.as_mut());
+pub fn get_signature_data<S>(item: &DataItem<S>) -> [u8; 48] {
+    let signature_type = item.base.signature_type.to_string();
+    let mut chunks = get_signature_data_prefix(item, &signature_type);
+    chunks.push(DeepHashChunk::Blob(item.base.raw_data.as_ref()));
 
-    return CryptoDriver::deep_hash(&vec);
+    deep_hash(&DeepHashChunk::List(chunks))
 }
+
+/// Same digest as `get_signature_data`, with the data field read from `data` instead of `raw_data`
+pub fn get_signature_data_streaming<S, R: Read>(item: &DataItem<S>, data: &mut R, data_length: u64) -> Result<[u8; 48], ArBundleErrors> {
+    let signature_type = item.base.signature_type.to_string();
+    deep_hash_stream(&get_signature_data_prefix(item, &signature_type), data, data_length)
+}
+
+pub async fn get_signature_data_streaming_async<S, R: AsyncRead + Unpin>(item: &DataItem<S>, data: &mut R, data_length: u64) -> Result<[u8; 48], ArBundleErrors> {
+    let signature_type = item.base.signature_type.to_string();
+    deep_hash_stream_async(&get_signature_data_prefix(item, &signature_type), data, data_length).await
+}
+
+/// Same digest as `get_signature_data`, for a data field already hashed with `DeepHashBlob`
+pub fn get_signature_data_with_blob<S>(item: &DataItem<S>, data_hash: [u8; 48]) -> [u8; 48] {
+    let signature_type = item.base.signature_type.to_string();
+    deep_hash_list_with_blob(&get_signature_data_prefix(item, &signature_type), data_hash)
+}
+
+/// Every signed field except the data, which always comes last
+fn get_signature_data_prefix<'a, S>(item: &'a DataItem<S>, signature_type: &'a str) -> Vec<DeepHashChunk<'a>> {
+    vec![
+        DeepHashChunk::Blob(CryptoDriver::string_to_buffer("dataitem")),
+        DeepHashChunk::Blob(CryptoDriver::string_to_buffer("1")),
+        DeepHashChunk::Blob(CryptoDriver::string_to_buffer(signature_type)),
+        DeepHashChunk::Blob(item.base.raw_owner.as_ref()),
+        DeepHashChunk::Blob(item.base.raw_target.as_ref()),
+        DeepHashChunk::Blob(item.base.raw_anchor.as_ref()),
+        DeepHashChunk::Blob(item.base.raw_tags.as_ref())
+    ]
+}
\ No newline at end of file
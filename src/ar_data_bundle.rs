@@ -1,22 +1,156 @@
-use crate::{ar_data_base::get_signature_data, data_item::DataItem, key_utils::get_crypto_driver, signing::signer::SignerMaker};
+use arweave_rs::crypto::hash::sha256;
+use serde::{Deserialize, Serialize};
+use crate::{
+    ar_data_base::get_signature_data,
+    bundle::Bundle,
+    data_item::{SignedDataItem, UnsignedDataItem},
+    errors::ArBundleErrors,
+    signing::{constants::get_index_to_type, signer::{AsyncSigner, Signer}},
+    types::TxId,
+    utils::long_to_32_byte_array
+};
 
 #[derive(Debug)]
-pub struct ArDataBundles { 
-    signature: Vec<u8>, 
-    id: Vec<u8>
+pub struct ArDataBundles {
+    pub signature: Vec<u8>,
+    pub id: TxId
 }
 
-pub fn get_signature_and_id<T: SignerMaker>(item: &mut DataItem, signer: &T) -> ArDataBundles {
+pub fn get_signature_and_id<T: Signer + ?Sized>(item: &UnsignedDataItem, signer: &T) -> Result<ArDataBundles, ArBundleErrors> {
     let signature_data = get_signature_data(item);
-  
-    let signature_bytes = signer.sign(&signature_data.to_vec()).unwrap();
-    let id_bytes = get_crypto_driver(item.base.keypair_path.as_ref()).hash(&signature_bytes);
-  
-    ArDataBundles { signature: signature_bytes.to_vec(), id: id_bytes.to_vec() }
+
+    let signature_bytes = signer.sign(&signature_data)?;
+    let id_bytes = sha256(&signature_bytes);
+
+    Ok(ArDataBundles { signature: signature_bytes, id: TxId::new(id_bytes) })
+}
+
+/// Consumes the unsigned item, the signed one that comes back is the only one that can be bundled
+pub fn sign<T: Signer + ?Sized>(item: UnsignedDataItem, signer: &T) -> Result<SignedDataItem, ArBundleErrors> {
+    let ArDataBundles { signature, .. } = get_signature_and_id(&item, signer)?;
+    item.into_signed(&signature)
+}
+
+/// Same as `sign`, awaiting the signer so the signature can come from a remote service
+pub async fn sign_async<T: AsyncSigner + ?Sized>(item: UnsignedDataItem, signer: &T) -> Result<SignedDataItem, ArBundleErrors> {
+    let signature_data = get_signature_data(&item);
+    let signature = signer.sign(&signature_data).await?;
+    item.into_signed(&signature)
+}
+
+/// What an offline signer needs to sign an item it never sees in full: the message to sign (the item's
+/// deep hash) and, so the request can be turned back into an item anywhere, the unsigned item itself.
+/// Byte values are base64url encoded so the request can travel as JSON.
+#[derive(Serialize, Deserialize)]
+pub struct SigningRequest {
+    pub signature_type: i64,
+    pub signature_length: usize,
+    pub owner: String,
+    pub signature_data: String,
+    pub unsigned_item: String
+}
+
+impl SigningRequest {
+    pub fn to_json(&self) -> Result<String, ArBundleErrors> {
+        serde_json::to_string(self).map_err(|e| ArBundleErrors::IoFailure(e.into()))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ArBundleErrors> {
+        serde_json::from_str(json).map_err(|e| ArBundleErrors::IoFailure(e.into()))
+    }
+}
+
+/// First phase of detached signing, exports the item and the message the external signer must sign
+pub fn get_signing_request(item: &UnsignedDataItem) -> Result<SigningRequest, ArBundleErrors> {
+    let signature_type = item.get_signature_type()?;
+    Ok(SigningRequest {
+        signature_type: signature_type as i64,
+        signature_length: item.base.raw_signature.as_ref().len(),
+        owner: item.base.owner.as_ref().clone(),
+        signature_data: base64_url::encode(&get_signature_data(item)),
+        unsigned_item: base64_url::encode(item.binary())
+    })
+}
+
+/// Second phase of detached signing, rebuilds the item from the request and applies the externally produced signature.
+/// Every field of the request must describe the item it carries.
+pub fn apply_signing_request(request: &SigningRequest, signature: &[u8]) -> Result<SignedDataItem, ArBundleErrors> {
+    let binary = base64_url::decode(&request.unsigned_item).map_err(|e| ArBundleErrors::IoFailure(
+        std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+    ))?;
+    let item = UnsignedDataItem::from_bytes(binary)?;
+    let expected = get_signing_request(&item)?;
+    let mismatched_field = if request.signature_type != expected.signature_type {
+        Some("signature_type")
+    } else if request.signature_length != expected.signature_length {
+        Some("signature_length")
+    } else if request.owner != expected.owner {
+        Some("owner")
+    } else if request.signature_data != expected.signature_data {
+        Some("signature_data")
+    } else {
+        None
+    };
+    if let Some(field) = mismatched_field {
+        return Err(ArBundleErrors::IoFailure(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Signing request {} does not match the item it carries", field)
+        )));
+    }
+
+    apply_signature(item, signature)
+}
+
+/// Writes a signature produced outside this process into the item's signature slot, once it checks out
+/// against the item's owner
+pub fn apply_signature(item: UnsignedDataItem, signature: &[u8]) -> Result<SignedDataItem, ArBundleErrors> {
+    let verify = match get_index_to_type().get(&item.get_signature_type()?) {
+        Some(verify) => verify,
+        None => return Err(ArBundleErrors::SignatureConfigTypeNotFound)
+    };
+    let signed = item.into_signed(signature)?;
+    if !verify(signed.raw_owner(), &get_signature_data(&signed), signature) {
+        return Err(ArBundleErrors::InvalidSignature);
+    }
+
+    Ok(signed)
+}
+
+/// Signs every item and packs them into a bundle, see `bundle`
+pub fn bundle_and_sign<T: Signer + ?Sized>(data_items: Vec<UnsignedDataItem>, signer: &T) -> Result<Bundle, ArBundleErrors> {
+    let signed_items = data_items.into_iter()
+        .map(|item| sign(item, signer))
+        .collect::<Result<Vec<SignedDataItem>, ArBundleErrors>>()?;
+
+    bundle(signed_items)
+}
+
+/// Same as `bundle_and_sign`, signing the items one after another through an `AsyncSigner`
+pub async fn bundle_and_sign_async<T: AsyncSigner + ?Sized>(data_items: Vec<UnsignedDataItem>, signer: &T) -> Result<Bundle, ArBundleErrors> {
+    let mut signed_items = Vec::with_capacity(data_items.len());
+    for item in data_items {
+        signed_items.push(sign_async(item, signer).await?);
+    }
+
+    bundle(signed_items)
 }
 
-pub fn sign<T: SignerMaker>(item: &mut DataItem, signer: &T) -> Result<Vec<u8>, ArDataBundles> {
-    let ArDataBundles { signature, id } = get_signature_and_id(item, signer);
-    item.get_raw()[2..signature.len()].copy_from_slice(&signature);
-    Ok(id)
+/// Packs signed items into an ANS-104 bundle: a 32 byte item count, then a 64 byte header
+/// (32 byte size + 32 byte id) per item, then the items themselves
+pub fn bundle(data_items: Vec<SignedDataItem>) -> Result<Bundle, ArBundleErrors> {
+    let mut headers: Vec<u8> = Vec::with_capacity(64 * data_items.len());
+    let mut binaries: Vec<u8> = vec![];
+
+    for item in data_items.iter() {
+        headers.extend_from_slice(&long_to_32_byte_array(item.get_raw_size() as i64));
+        headers.extend_from_slice(item.id().as_bytes());
+        binaries.append(&mut item.get_raw());
+    }
+
+    let mut buffer: Vec<u8> = Vec::with_capacity(32 + headers.len() + binaries.len());
+    buffer.extend_from_slice(&long_to_32_byte_array(data_items.len() as i64));
+    buffer.append(&mut headers);
+    buffer.append(&mut binaries);
+
+    Bundle::from_bytes(buffer)
 }
\ No newline at end of file
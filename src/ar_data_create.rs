@@ -1,12 +1,21 @@
-use crate::data_item::DataItem;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use arweave_rs::crypto::hash::sha256;
+use rand::RngCore;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+use crate::ar_data_base::get_signature_data_with_blob;
+use crate::ar_data_bundle::sign;
+use crate::data_item::{SignedDataItem, UnsignedDataItem};
+use crate::deep_hash::{stream_too_long, DeepHashBlob, STREAM_CHUNK_SIZE};
 use crate::errors::ArBundleErrors;
-use crate::signing::signer::SignerMaker;
-use crate::tags::{serialize_tags, Tag};
+use crate::signing::signer::{AsyncSigner, Signer};
+use crate::tags::{serialize_tags, Tag, TagPolicy};
+use crate::types::{Anchor, TxId};
 use crate::utils::{long_to_8_byte_array, short_to_2_byte_array};
 
 pub struct DataItemCreateOptions {
-    pub target: Option<String>,
-    pub anchor: Option<String>,
+    pub target: Option<TxId>,
+    pub anchor: Option<Anchor>,
     pub tags: Option<Vec<Tag>>
 }
 
@@ -15,105 +24,267 @@ pub enum Data {
     BinaryData(Vec<u8>)
 }
 
-pub fn create_data<T: SignerMaker>(data: Data, signer: &T, opts: Option<&DataItemCreateOptions>) -> Result<DataItem, ArBundleErrors> {
-    let _owner = &signer.get_public_key();
-
-    let _target = if opts.is_some() && opts.unwrap().target.is_some() {
-        let target = &opts.unwrap().target.as_ref().unwrap();
-        let mut output = vec![];
-        base64_url::encode_to_vec(target, &mut output);
-        Some(output)
-    } else { None };
-    let target_length = 1 + (if let Some(_target) = _target.clone() {
-        _target.len()
-    } else { 0 });
-    let _anchor = if opts.is_some() && opts.unwrap().anchor.is_some() {
-        Some(opts.unwrap().anchor.as_ref().unwrap().as_bytes())
-    } else { None };
-    let anchor_length = 1 + (if let Some(_anchor) = _anchor.clone() {
-        _anchor.len()
-    } else { 0 });
-    let _tags = if opts.is_some() && opts.unwrap().tags.is_some() && opts.unwrap().tags.as_ref().unwrap().len() > 0 {
-        match serialize_tags(opts.unwrap().tags.as_ref().unwrap()) {
-            Ok(_tags) => Some(_tags),
-            Err(_) => None
-        }
-    } else {
-        None
-    };
-    let tags_length = 16 + (if let Some(_tags) = _tags.clone() {
-        _tags.len()
-    } else { 0 });
-    let _data = match data {
-        Data::StringData(string_data) => string_data.as_bytes().to_vec(),
-        Data::BinaryData(binary_data) => binary_data
-    };
-    let data_length = _data.len();
-
-    let length = 2 + signer.get_signature_length() + signer.get_owner_length() + target_length + anchor_length + tags_length + data_length;
-    let mut bytes = vec![0; length];
-
-    bytes[0..2].copy_from_slice(&short_to_2_byte_array(signer.get_signature_type()).unwrap());
-    bytes[2..2 + signer.get_signature_length() as usize].fill(0);
-
-    if _owner.len() != signer.get_owner_length() as usize {
-        return Err(
-            ArBundleErrors::IoFailure(
-                std::io::Error::new(std::io::ErrorKind::Other, 
-                    format!("Owner must be {} bytes, but was incorrectly {}", signer.get_owner_length(), _owner.len())
-                )
-            )
-        );
-    }
-    bytes[2 + signer.get_signature_length()..2 + signer.get_signature_length() + signer.get_owner_length()].copy_from_slice(&_owner);
-    
-    let position = 2 + signer.get_signature_length() + signer.get_owner_length();
-    bytes[position] = if let Some(_target) = _target.clone() { 1 } else { 0 };
-    if let Some(_target) = _target {
-        if _target.len() != 32 {
-            return Err(ArBundleErrors::IoFailure(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!("Target must be 32 bytes but was incorrectly {}", _target.len())
-                )
-            ))
+pub fn create_data<T: Signer + ?Sized>(data: Data, signer: &T, opts: Option<&DataItemCreateOptions>) -> Result<UnsignedDataItem, ArBundleErrors> {
+    create_data_with_policy(data, signer, opts, &TagPolicy::default())
+}
+
+/// Same as `create_data`, with the tags held to `tag_policy` instead of the ANS-104 limits
+pub fn create_data_with_policy<T: Signer + ?Sized>(
+    data: Data,
+    signer: &T,
+    opts: Option<&DataItemCreateOptions>,
+    tag_policy: &TagPolicy
+) -> Result<UnsignedDataItem, ArBundleErrors> {
+    DataItemBuilder::from_options(data, opts)?.tag_policy(*tag_policy).build_unsigned(signer)
+}
+
+/// Same as `create_data`, for signers that have to fetch their public key asynchronously
+pub async fn create_data_async<T: AsyncSigner + ?Sized>(data: Data, signer: &T, opts: Option<&DataItemCreateOptions>) -> Result<UnsignedDataItem, ArBundleErrors> {
+    let builder = DataItemBuilder::from_options(data, opts)?;
+    let owner = signer.get_public_key().await?;
+    let mut bytes = builder.header(signer.signature_type() as i64, signer.get_signature_length(), &owner, signer.get_owner_length())?;
+    bytes.extend_from_slice(&builder.data);
+
+    UnsignedDataItem::from_bytes(bytes)
+}
+
+/// Fluent alternative to `create_data`:
+/// `DataItemBuilder::new().data_from_bytes(data).tag("Content-Type", "text/plain").sign(&signer)?`
+#[derive(Default)]
+pub struct DataItemBuilder {
+    data: Vec<u8>,
+    target: Option<TxId>,
+    anchor: Option<Anchor>,
+    tags: Vec<Tag>,
+    tag_policy: TagPolicy
+}
+
+impl DataItemBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn data_from_bytes(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    pub fn data_from_reader<R: Read>(mut self, reader: &mut R) -> Result<Self, ArBundleErrors> {
+        let mut data = vec![];
+        reader.read_to_end(&mut data).map_err(ArBundleErrors::IoFailure)?;
+        self.data = data;
+        Ok(self)
+    }
+
+    pub fn data_from_file<P: AsRef<Path>>(mut self, path: P) -> Result<Self, ArBundleErrors> {
+        self.data = std::fs::read(path).map_err(ArBundleErrors::IoFailure)?;
+        Ok(self)
+    }
+
+    pub fn target(mut self, target: TxId) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = Some(anchor);
+        self
+    }
+
+    /// Anchors the item with 32 random bytes, so otherwise identical items get distinct ids
+    pub fn random_anchor(mut self) -> Self {
+        let mut anchor = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut anchor);
+        self.anchor = Some(Anchor::new(anchor));
+        self
+    }
+
+    pub fn tag(mut self, name: &str, value: &str) -> Self {
+        self.tags.push(Tag { name: Some(name.to_string()), value: Some(value.to_string()) });
+        self
+    }
+
+    /// Holds the tags to stricter limits than ANS-104, checked when the item is built
+    pub fn tag_policy(mut self, tag_policy: TagPolicy) -> Self {
+        self.tag_policy = tag_policy;
+        self
+    }
+
+    /// Lays out the item for `signer`'s owner with a zero filled signature slot, nothing is signed
+    pub fn build_unsigned<T: Signer + ?Sized>(self, signer: &T) -> Result<UnsignedDataItem, ArBundleErrors> {
+        let mut bytes = self.header(
+            signer.signature_type() as i64,
+            signer.get_signature_length(),
+            &signer.get_public_key(),
+            signer.get_owner_length()
+        )?;
+        bytes.extend_from_slice(&self.data);
+
+        UnsignedDataItem::from_bytes(bytes)
+    }
+
+    pub fn sign<T: Signer + ?Sized>(self, signer: &T) -> Result<SignedDataItem, ArBundleErrors> {
+        sign(self.build_unsigned(signer)?, signer)
+    }
+
+    fn from_options(data: Data, opts: Option<&DataItemCreateOptions>) -> Result<Self, ArBundleErrors> {
+        let builder = match data {
+            Data::StringData(string_data) => Self::new().data_from_bytes(string_data),
+            Data::BinaryData(binary_data) => Self::new().data_from_bytes(binary_data)
+        };
+        let opts = match opts {
+            Some(opts) => opts,
+            None => return Ok(builder)
+        };
+
+        let mut builder = builder;
+        builder.target = opts.target;
+        builder.anchor = opts.anchor;
+        if let Some(tags) = &opts.tags {
+            builder.tags = tags.clone();
         }
-        bytes[position + 1..position + 1 + _target.len()].copy_from_slice(&_target);
-    }
-
-    let anchor_start = position + target_length;
-    let mut tags_start = anchor_start + 1;
-    bytes[anchor_start] = if let Some(_anchor) = _anchor.clone() { 1 } else { 0 };
-    if let Some(_anchor) = _anchor.clone() {
-        tags_start += _anchor.len();
-        if _anchor.len() != 32 {
-            return Err(ArBundleErrors::IoFailure(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!("Anchor must be 32 bytes")
-                )
-            ))
+        Ok(builder)
+    }
+
+    /// Builds everything that precedes the data field, leaving the signature slot zero filled
+    fn header(&self, signature_type: i64, signature_length: usize, owner: &[u8], owner_length: usize) -> Result<Vec<u8>, ArBundleErrors> {
+        if owner.len() != owner_length {
+            return Err(ArBundleErrors::IoFailure(std::io::Error::other(
+                format!("Owner must be {} bytes, but was incorrectly {}", owner_length, owner.len())
+            )));
         }
-        bytes[anchor_start + 1..anchor_start + 1 + _anchor.len()].copy_from_slice(&_anchor);
+        self.tag_policy.validate(&self.tags).map_err(ArBundleErrors::InvalidTag)?;
+        let tags = if self.tags.is_empty() { vec![] } else { serialize_tags(&self.tags)? };
+
+        let mut bytes = Vec::with_capacity(2 + signature_length + owner_length + 66 + 16 + tags.len());
+        bytes.extend_from_slice(&short_to_2_byte_array(signature_type)?);
+        bytes.resize(2 + signature_length, 0);
+        bytes.extend_from_slice(owner);
+        write_optional_field(&mut bytes, self.target.as_ref().map(TxId::as_bytes));
+        write_optional_field(&mut bytes, self.anchor.as_ref().map(Anchor::as_bytes));
+        bytes.extend_from_slice(&long_to_8_byte_array(self.tags.len() as i64));
+        bytes.extend_from_slice(&long_to_8_byte_array(tags.len() as i64));
+        bytes.extend_from_slice(&tags);
+
+        Ok(bytes)
     }
+}
 
-    let tags_length_or_0 = if opts.is_some() && opts.unwrap().tags.is_some() {
-        opts.unwrap().tags.as_ref().unwrap().len()
-    } else { 0 };
-    let long_to_8_byte_array_result = long_to_8_byte_array(tags_length_or_0 as i64);
-    bytes[tags_start..tags_start + long_to_8_byte_array_result.len()].copy_from_slice(&long_to_8_byte_array_result);
-    let tags_bytes_length = if _tags.clone().is_some() {
-        _tags.clone().unwrap().len()
-    } else { 0 };
-    let bytes_count = long_to_8_byte_array(tags_bytes_length as i64);
-    bytes[tags_start + 8..tags_start + 8 + bytes_count.len()].copy_from_slice(&bytes_count);
-    if _tags.clone().is_some() {
-        bytes[tags_start + 16..tags_start + 16 + _tags.clone().unwrap().len()].copy_from_slice(&_tags.unwrap());
+/// Presence byte, then the 32 byte value when there is one
+/// The signature goes into a slot sized from the header, a signer returning another length would corrupt the item
+fn check_signature_length(signature: &[u8], signature_length: usize) -> Result<(), ArBundleErrors> {
+    if signature.len() != signature_length {
+        return Err(ArBundleErrors::IoFailure(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Signature must be {} bytes but was {}", signature_length, signature.len())
+        )));
     }
+    Ok(())
+}
 
-    let data_start = tags_start + tags_length;
+fn write_optional_field(bytes: &mut Vec<u8>, field: Option<&[u8; 32]>) {
+    match field {
+        Some(field) => {
+            bytes.push(1);
+            bytes.extend_from_slice(field);
+        },
+        None => bytes.push(0)
+    }
+}
+
+/// Creates and signs a data item whose `data_length` bytes of data are streamed from `data` into `output`,
+/// so the payload never has to be held in memory. The header is written first with an empty signature slot,
+/// which is filled in once the data has been hashed. Returns the id of the signed item.
+/// `data` must hold exactly `data_length` bytes, wrap it in `Read::take` to stream only a prefix.
+pub fn create_and_sign_streaming<T: Signer + ?Sized, R: Read, W: Write + Seek>(
+    data: &mut R,
+    data_length: u64,
+    signer: &T,
+    opts: Option<&DataItemCreateOptions>,
+    output: &mut W
+) -> Result<TxId, ArBundleErrors> {
+    let header = DataItemBuilder::from_options(Data::BinaryData(vec![]), opts)?.header(
+        signer.signature_type() as i64,
+        signer.get_signature_length(),
+        &signer.get_public_key(),
+        signer.get_owner_length()
+    )?;
+    let header_item = UnsignedDataItem::from_bytes(header.clone())?;
+
+    let item_start = output.stream_position().map_err(ArBundleErrors::IoFailure)?;
+    output.write_all(&header).map_err(ArBundleErrors::IoFailure)?;
+    let mut blob = DeepHashBlob::new(data_length);
+    let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+    while blob.remaining() > 0 {
+        let to_read = blob.remaining().min(STREAM_CHUNK_SIZE as u64) as usize;
+        let read = match data.read(&mut buffer[..to_read]) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(ArBundleErrors::IoFailure(e))
+        };
+        output.write_all(&buffer[..read]).map_err(ArBundleErrors::IoFailure)?;
+        blob.update(&buffer[..read])?;
+    }
+    let data_hash = blob.finish()?;
+    if data.read(&mut buffer[..1]).map_err(ArBundleErrors::IoFailure)? > 0 {
+        return Err(stream_too_long(data_length));
+    }
+
+    let signature = signer.sign(&get_signature_data_with_blob(&header_item, data_hash))?;
+    check_signature_length(&signature, signer.get_signature_length())?;
+    output.seek(SeekFrom::Start(item_start + 2)).map_err(ArBundleErrors::IoFailure)?;
+    output.write_all(&signature).map_err(ArBundleErrors::IoFailure)?;
+    output.seek(SeekFrom::Start(item_start + header.len() as u64 + data_length)).map_err(ArBundleErrors::IoFailure)?;
+
+    Ok(TxId::new(sha256(&signature)))
+}
+
+/// Async version of `create_and_sign_streaming`
+pub async fn create_and_sign_streaming_async<T, R, W>(
+    data: &mut R,
+    data_length: u64,
+    signer: &T,
+    opts: Option<&DataItemCreateOptions>,
+    output: &mut W
+) -> Result<TxId, ArBundleErrors>
+where
+    T: AsyncSigner + ?Sized,
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + AsyncSeek + Unpin
+{
+    let header = DataItemBuilder::from_options(Data::BinaryData(vec![]), opts)?.header(
+        signer.signature_type() as i64,
+        signer.get_signature_length(),
+        &signer.get_public_key().await?,
+        signer.get_owner_length()
+    )?;
+    let header_item = UnsignedDataItem::from_bytes(header.clone())?;
+
+    let item_start = output.stream_position().await.map_err(ArBundleErrors::IoFailure)?;
+    output.write_all(&header).await.map_err(ArBundleErrors::IoFailure)?;
+    let mut blob = DeepHashBlob::new(data_length);
+    let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+    while blob.remaining() > 0 {
+        let to_read = blob.remaining().min(STREAM_CHUNK_SIZE as u64) as usize;
+        let read = data.read(&mut buffer[..to_read]).await.map_err(ArBundleErrors::IoFailure)?;
+        if read == 0 {
+            break;
+        }
+        output.write_all(&buffer[..read]).await.map_err(ArBundleErrors::IoFailure)?;
+        blob.update(&buffer[..read])?;
+    }
+    let data_hash = blob.finish()?;
+    if data.read(&mut buffer[..1]).await.map_err(ArBundleErrors::IoFailure)? > 0 {
+        return Err(stream_too_long(data_length));
+    }
 
-    bytes[data_start..data_start + _data.len()].copy_from_slice(&_data);
+    let signature = signer.sign(&get_signature_data_with_blob(&header_item, data_hash)).await?;
+    check_signature_length(&signature, signer.get_signature_length())?;
+    output.seek(SeekFrom::Start(item_start + 2)).await.map_err(ArBundleErrors::IoFailure)?;
+    output.write_all(&signature).await.map_err(ArBundleErrors::IoFailure)?;
+    output.seek(SeekFrom::Start(item_start + header.len() as u64 + data_length)).await.map_err(ArBundleErrors::IoFailure)?;
+    output.flush().await.map_err(ArBundleErrors::IoFailure)?;
 
-    Ok(DataItem::new(bytes, &signer.get_keypair_path()))
+    Ok(TxId::new(sha256(&signature)))
 }
\ No newline at end of file
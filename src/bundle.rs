@@ -0,0 +1,154 @@
+use crate::{
+    data_item::SignedDataItem,
+    data_item_ref::DataItemRef,
+    errors::ArBundleErrors,
+    tags::TagPolicy,
+    types::TxId,
+    utils::byte_array_to_long
+};
+
+const HEADER_START: usize = 32;
+
+/// An ANS-104 bundle. `from_bytes` only checks the framing, items are parsed when they are read and
+/// each one must hash to the id its header entry claims.
+pub struct Bundle {
+    binary: Vec<u8>,
+    sizes: Vec<usize>
+}
+
+impl Bundle {
+    /// Checks that the header is complete and that the items it describes fill the rest of the binary exactly
+    pub fn from_bytes(binary: Vec<u8>) -> Result<Self, ArBundleErrors> {
+        if binary.len() < HEADER_START {
+            return Err(ArBundleErrors::Truncated { field: "item count", offset: 0 });
+        }
+
+        let length = match byte_array_to_long(&binary[0..HEADER_START]) {
+            Some(length) if (length as usize) <= (binary.len() - HEADER_START) / 64 => length as usize,
+            Some(_) => return Err(ArBundleErrors::Truncated { field: "item headers", offset: HEADER_START }),
+            None => return Err(ArBundleErrors::LengthOutOfRange { field: "item count", offset: 0 })
+        };
+
+        let mut sizes = Vec::with_capacity(length);
+        let mut items_end = HEADER_START + 64 * length;
+        for index in 0..length {
+            let header_start = HEADER_START + 64 * index;
+            let size = byte_array_to_long(&binary[header_start..header_start + 32])
+                .and_then(|size| items_end.checked_add(size as usize).map(|end| (size as usize, end)));
+            match size {
+                Some((size, end)) if end <= binary.len() => {
+                    sizes.push(size);
+                    items_end = end;
+                },
+                Some(_) => return Err(ArBundleErrors::Truncated { field: "item", offset: items_end }),
+                None => return Err(ArBundleErrors::LengthOutOfRange { field: "item size", offset: header_start })
+            }
+        }
+        if items_end != binary.len() {
+            return Err(ArBundleErrors::TrailingBytes { offset: items_end });
+        }
+
+        Ok(Self { binary, sizes })
+    }
+
+    pub fn length(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn get_raw(&self) -> Vec<u8> {
+        self.binary.clone()
+    }
+
+    pub fn items(&self) -> Result<Vec<SignedDataItem>, ArBundleErrors> {
+        self.items_with_policy(&TagPolicy::default())
+    }
+
+    /// Same as `items`, with tags held to `tag_policy` instead of the ANS-104 limits
+    pub fn items_with_policy(&self, tag_policy: &TagPolicy) -> Result<Vec<SignedDataItem>, ArBundleErrors> {
+        (0..self.length())
+            .map(|index| self.get_by_index_with_policy(index, tag_policy))
+            .collect()
+    }
+
+    pub fn get_sizes(&self) -> Vec<usize> {
+        self.sizes.clone()
+    }
+
+    pub fn get_ids(&self) -> Vec<TxId> {
+        (0..self.length())
+            .map(|index| self.get_raw_id_by(index))
+            .collect()
+    }
+
+    pub fn get_id_by(&self, index: usize) -> Result<TxId, ArBundleErrors> {
+        if index >= self.length() {
+            return Err(ArBundleErrors::DataItemNotFound);
+        }
+        Ok(self.get_raw_id_by(index))
+    }
+
+    pub fn get_by_index(&self, index: usize) -> Result<SignedDataItem, ArBundleErrors> {
+        self.get_by_index_with_policy(index, &TagPolicy::default())
+    }
+
+    pub fn get_by_index_with_policy(&self, index: usize, tag_policy: &TagPolicy) -> Result<SignedDataItem, ArBundleErrors> {
+        let item = self.get_ref_by_index(index)?;
+        SignedDataItem::from_bytes_with_policy(item.as_bytes().to_vec(), tag_policy)
+    }
+
+    /// Same as `items`, without copying any item out of the bundle
+    pub fn item_refs(&self) -> Result<Vec<DataItemRef<'_>>, ArBundleErrors> {
+        let mut data_item_start = self.get_bundle_start();
+        self.sizes
+            .iter()
+            .map(|size| {
+                let slice = &self.binary[data_item_start..data_item_start + size];
+                data_item_start += size;
+                DataItemRef::new(slice)
+            })
+            .enumerate()
+            .map(|(index, item)| self.check_id(index, item?))
+            .collect()
+    }
+
+    pub fn get_ref_by_index(&self, index: usize) -> Result<DataItemRef<'_>, ArBundleErrors> {
+        if index >= self.length() {
+            return Err(ArBundleErrors::DataItemNotFound);
+        }
+
+        let offset: usize = self.sizes[..index].iter().sum();
+        let data_item_start = self.get_bundle_start() + offset;
+        let item = DataItemRef::new(&self.binary[data_item_start..data_item_start + self.sizes[index]])?;
+        self.check_id(index, item)
+    }
+
+    pub fn get_by_id(&self, id: &TxId) -> Result<SignedDataItem, ArBundleErrors> {
+        self.get_by_id_with_policy(id, &TagPolicy::default())
+    }
+
+    pub fn get_by_id_with_policy(&self, id: &TxId, tag_policy: &TagPolicy) -> Result<SignedDataItem, ArBundleErrors> {
+        match self.get_ids().iter().position(|item_id| item_id == id) {
+            Some(index) => self.get_by_index_with_policy(index, tag_policy),
+            None => Err(ArBundleErrors::DataItemNotFound)
+        }
+    }
+
+    /// A header id that does not match its item would let `get_by_id` hand out some other item
+    fn check_id<'a>(&self, index: usize, item: DataItemRef<'a>) -> Result<DataItemRef<'a>, ArBundleErrors> {
+        if item.id() != self.get_raw_id_by(index) {
+            return Err(ArBundleErrors::BundleIdMismatch { index });
+        }
+        Ok(item)
+    }
+
+    fn get_raw_id_by(&self, index: usize) -> TxId {
+        let id_start = HEADER_START + 64 * index + 32;
+        let mut id = [0u8; 32];
+        id.copy_from_slice(&self.binary[id_start..id_start + 32]);
+        TxId::new(id)
+    }
+
+    fn get_bundle_start(&self) -> usize {
+        HEADER_START + 64 * self.length()
+    }
+}
\ No newline at end of file
@@ -1,7 +1,8 @@
+use std::fmt::Display;
 use async_trait::async_trait;
 use serde::Serialize;
-use crate::signing::signer::SignerMaker;
 use crate::tags::Tag;
+use crate::types::{Anchor, TxId};
 
 #[derive(Serialize)]
 pub enum ResolvesTo<T> {
@@ -13,7 +14,7 @@ pub enum ResolvesTo<T> {
 impl<T> AsRef<T> for ResolvesTo<T> {
     fn as_ref(&self) -> &T {
         match self {
-            ResolvesTo::Item(val) => &val,
+            ResolvesTo::Item(val) => val,
         }
     }
 }
@@ -26,10 +27,10 @@ impl<T> AsMut<T> for ResolvesTo<T> {
     }
 }
 
-impl ToString for ResolvesTo<i64> {
-    fn to_string(&self) -> String {
+impl Display for ResolvesTo<i64> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ResolvesTo::Item(val) => format!("{}", val),
+            ResolvesTo::Item(val) => write!(f, "{}", val),
         }
     }
 }
@@ -44,9 +45,9 @@ pub struct BundleItem {
   pub owner: ResolvesTo<String>,
   pub owner_length: ResolvesTo<i64>,
   pub raw_target: ResolvesTo<Vec<u8>>,
-  pub target: ResolvesTo<String>,
+  pub target: ResolvesTo<Option<TxId>>,
   pub raw_anchor: ResolvesTo<Vec<u8>>,
-  pub anchor: ResolvesTo<String>,
+  pub anchor: ResolvesTo<Option<Anchor>>,
   pub raw_tags: ResolvesTo<Vec<u8>>,
   pub tags: ResolvesTo<Vec<Tag>>,
   pub raw_data: ResolvesTo<Vec<u8>>,
@@ -56,11 +57,7 @@ pub struct BundleItem {
 
 #[async_trait]
 pub trait BundleItemFn {
-    fn sign<T: SignerMaker>(&mut self, signer: &T) -> [u8; 32];
+    fn is_valid(&self) -> bool;
 
-    fn is_valid<T: SignerMaker>(&self, signer: &T) -> bool;
-
-    fn verify<T: SignerMaker>(&self, _args: &Vec<u8>, _signer: &T) -> bool {
-        unimplemented!("You must implement `verify`")
-    }
+    fn verify(&self, args: &[u8]) -> bool;
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 use once_cell::sync::OnceCell;
+use serde::Serialize;
 
-#[derive(Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize)]
 pub enum SignatureConfig {
     ARWEAVE = 1,
     ED25519 = 2,
@@ -1,30 +1,114 @@
-use std::any::Any;
+use std::{any::Any, marker::PhantomData};
 use crate::{
     ar_data_base::get_signature_data, 
     bundle_item::{BundleItem, BundleItemFn}, 
     constants::SignatureConfig, 
+    data_item_ref::{signature_type_from_bytes, DataItemRef},
     errors::ArBundleErrors, 
-    signing::signer::SignerMaker, 
-    tags::deserialize_tags, utils::byte_array_to_long
+    key_utils::owner_to_address,
+    tags::{Tag, TagPolicy},
+    signing::{
+        chains::{
+            ethereum_signer::checksum_address,
+            injected_aptos_signer::aptos_address,
+            multi_aptos_signer::multi_aptos_address
+        },
+        constants::get_index_to_type
+    }, 
+    types::{Anchor, TxId}
 };
 use async_trait::async_trait;
-use serde::Serialize;
+use serde::{ser::SerializeStruct, Serialize, Serializer};
 use crate::bundle_item::ResolvesTo;
-use crate::ar_data_bundle::sign;
 use arweave_rs::crypto::hash::sha256;
 
 pub const MAX_TAG_BYTES: usize = 4096;
 pub const MIN_BINARY_SIZE: usize = 80;
 
-#[derive(Serialize)]
-pub struct DataItem {
-    pub base: BundleItem,
+#[derive(Debug, Serialize)]
+pub struct VerificationReport {
+    pub id: TxId,
+    pub signature_type: SignatureConfig,
+    pub owner: String,
+    pub tag_count: usize,
+    pub data_size: usize,
+    pub signature_valid: bool
+}
+
+impl VerificationReport {
+    pub fn is_valid(&self) -> bool {
+        self.signature_valid
+    }
+}
+
+/// State of a `DataItem` whose signature slot is still zero filled
+pub struct Unsigned;
+/// State of a `DataItem` that carries a signature, only these have an id and can be bundled or serialized
+pub struct Signed;
+
+/// A data item whose state is tracked in its type: only a `SignedDataItem` has an id and can be bundled
+/// or serialized, its fields can be read but not changed.
+///
+/// ```
+/// use ar_bundles::{ar_data_bundle::{bundle, sign}, ar_data_create::DataItemBuilder};
+/// use ar_bundles::signing::chains::ed25519_signer::Ed25519Signer;
+///
+/// let signer = Ed25519Signer::new(&[1u8; 32]).unwrap();
+/// let signed = sign(DataItemBuilder::new().build_unsigned(&signer).unwrap(), &signer).unwrap();
+/// let _ = signed.id();
+/// let _ = serde_json::to_string(&signed).unwrap();
+/// bundle(vec![signed]).unwrap();
+/// ```
+///
+/// An unsigned item has no id:
+///
+/// ```compile_fail
+/// use ar_bundles::{ar_data_create::DataItemBuilder, signing::chains::ed25519_signer::Ed25519Signer};
+///
+/// let signer = Ed25519Signer::new(&[1u8; 32]).unwrap();
+/// let unsigned = DataItemBuilder::new().build_unsigned(&signer).unwrap();
+/// let _ = unsigned.id();
+/// ```
+///
+/// can't be bundled:
+///
+/// ```compile_fail
+/// use ar_bundles::{ar_data_bundle::bundle, ar_data_create::DataItemBuilder};
+/// use ar_bundles::signing::chains::ed25519_signer::Ed25519Signer;
+///
+/// let signer = Ed25519Signer::new(&[1u8; 32]).unwrap();
+/// let unsigned = DataItemBuilder::new().build_unsigned(&signer).unwrap();
+/// bundle(vec![unsigned]);
+/// ```
+///
+/// and can't be serialized, not even through its fields:
+///
+/// ```compile_fail
+/// use ar_bundles::{ar_data_create::DataItemBuilder, signing::chains::ed25519_signer::Ed25519Signer};
+///
+/// let signer = Ed25519Signer::new(&[1u8; 32]).unwrap();
+/// let unsigned = DataItemBuilder::new().build_unsigned(&signer).unwrap();
+/// let _ = serde_json::to_string(&unsigned);
+/// ```
+///
+/// ```compile_fail
+/// use ar_bundles::{ar_data_create::DataItemBuilder, signing::chains::ed25519_signer::Ed25519Signer};
+///
+/// let signer = Ed25519Signer::new(&[1u8; 32]).unwrap();
+/// let unsigned = DataItemBuilder::new().build_unsigned(&signer).unwrap();
+/// let _ = serde_json::to_string(&unsigned.base);
+/// ```
+pub struct DataItem<S> {
+    pub(crate) base: BundleItem,
     binary: Vec<u8>,
-    _id: Option<Vec<u8>>
+    _state: PhantomData<S>
 }
 
-impl DataItem {
-    pub fn new(binary: Vec<u8>, keypair_path: &str) -> Self {
+pub type UnsignedDataItem = DataItem<Unsigned>;
+pub type SignedDataItem = DataItem<Signed>;
+
+impl<S> DataItem<S> {
+    fn new(binary: Vec<u8>, keypair_path: &str) -> Self {
         Self {
             base: BundleItem {
                 signature_type: ResolvesTo::Item(0),
@@ -35,9 +119,9 @@ impl DataItem {
                 owner: ResolvesTo::Item("".to_string()),
                 owner_length: ResolvesTo::Item(0),
                 raw_target: ResolvesTo::Item(vec![]),
-                target: ResolvesTo::Item("".to_string()),
+                target: ResolvesTo::Item(None),
                 raw_anchor: ResolvesTo::Item(vec![]),
-                anchor: ResolvesTo::Item("".to_string()),
+                anchor: ResolvesTo::Item(None),
                 raw_tags: ResolvesTo::Item(vec![]),
                 tags: ResolvesTo::Item(vec![]),
                 raw_data: ResolvesTo::Item(vec![]),
@@ -45,103 +129,247 @@ impl DataItem {
                 keypair_path: ResolvesTo::Item(keypair_path.to_string())
             },
             binary,
-            _id: None
+            _state: PhantomData
         }
     }
 
-    pub fn is_data_item(obj: Box<dyn Any>) -> bool {
-        let test = obj.downcast_ref::<DataItem>();
-        if test.is_some() {
-            return true;
-        }
-        false
+    /// Parses a serialized data item and fills in every raw and decoded field of `base`
+    fn parse(binary: Vec<u8>, tag_policy: &TagPolicy) -> Result<Self, ArBundleErrors> {
+        let view = DataItemRef::new(&binary)?;
+        let signature_type = view.signature_type();
+        let raw_signature = view.signature().to_vec();
+        let raw_owner = view.owner().to_vec();
+        let raw_target = view.target().unwrap_or_default().to_vec();
+        let raw_anchor = view.anchor().unwrap_or_default().to_vec();
+        let raw_tags = view.tags().to_vec();
+        let tags = view.decode_tags_with_policy(tag_policy)?;
+        let raw_data = view.data().to_vec();
+
+        let mut item = Self::new(binary, "");
+        item.base.signature_type = ResolvesTo::Item(signature_type as i64);
+        item.base.signature_length = ResolvesTo::Item(raw_signature.len() as i64);
+        item.base.owner_length = ResolvesTo::Item(raw_owner.len() as i64);
+        item.base.signature = ResolvesTo::Item(base64_url::encode(&raw_signature));
+        item.base.raw_signature = ResolvesTo::Item(raw_signature);
+        item.base.owner = ResolvesTo::Item(base64_url::encode(&raw_owner));
+        item.base.raw_owner = ResolvesTo::Item(raw_owner);
+        item.base.target = ResolvesTo::Item(TxId::try_from(raw_target.as_slice()).ok());
+        item.base.raw_target = ResolvesTo::Item(raw_target);
+        item.base.anchor = ResolvesTo::Item(Anchor::try_from(raw_anchor.as_slice()).ok());
+        item.base.raw_anchor = ResolvesTo::Item(raw_anchor);
+        item.base.tags = ResolvesTo::Item(tags);
+        item.base.raw_tags = ResolvesTo::Item(raw_tags);
+        item.base.data = ResolvesTo::Item(base64_url::encode(&raw_data));
+        item.base.raw_data = ResolvesTo::Item(raw_data);
+
+        Ok(item)
     }
 
-    pub fn get_signature_type(&self) -> Result<SignatureConfig, ArBundleErrors> {
-        let signature_type_val = byte_array_to_long(&self.binary[0..2]);
-        if SignatureConfig::ARWEAVE as i64 == signature_type_val {
-            return Ok(SignatureConfig::ARWEAVE);
-        } else if SignatureConfig::ED25519 as i64 == signature_type_val {
-            return Ok(SignatureConfig::ED25519);
-        } else if  SignatureConfig::ETHEREUM as i64 == signature_type_val {
-            return Ok(SignatureConfig::ETHEREUM);
-        } else if SignatureConfig::SOLANA as i64 == signature_type_val {
-            return Ok(SignatureConfig::SOLANA);
-        } else if SignatureConfig::INJECTEDAPTOS as i64 == signature_type_val {
-            return Ok(SignatureConfig::INJECTEDAPTOS);
-        } else if SignatureConfig::MULTIAPTOS as i64 == signature_type_val {
-            return Ok(SignatureConfig::MULTIAPTOS);
-        } else if SignatureConfig::TYPEDETHEREUM as i64 == signature_type_val {
-            return Ok(SignatureConfig::TYPEDETHEREUM);
+    /// Zero filled until the item is signed
+    pub fn raw_signature(&self) -> &[u8] {
+        self.base.raw_signature.as_ref()
+    }
+
+    /// base64url of `raw_signature`
+    pub fn signature(&self) -> &str {
+        self.base.signature.as_ref()
+    }
+
+    pub fn raw_owner(&self) -> &[u8] {
+        self.base.raw_owner.as_ref()
+    }
+
+    /// base64url of `raw_owner`
+    pub fn owner(&self) -> &str {
+        self.base.owner.as_ref()
+    }
+
+    /// Empty when the item has no target
+    pub fn raw_target(&self) -> &[u8] {
+        self.base.raw_target.as_ref()
+    }
+
+    pub fn target(&self) -> Option<TxId> {
+        *self.base.target.as_ref()
+    }
+
+    /// Empty when the item has no anchor
+    pub fn raw_anchor(&self) -> &[u8] {
+        self.base.raw_anchor.as_ref()
+    }
+
+    pub fn anchor(&self) -> Option<Anchor> {
+        *self.base.anchor.as_ref()
+    }
+
+    /// The Avro encoded tags
+    pub fn raw_tags(&self) -> &[u8] {
+        self.base.raw_tags.as_ref()
+    }
+
+    pub fn tags(&self) -> &[Tag] {
+        self.base.tags.as_ref()
+    }
+
+    pub fn raw_data(&self) -> &[u8] {
+        self.base.raw_data.as_ref()
+    }
+
+    /// base64url of `raw_data`
+    pub fn data(&self) -> &str {
+        self.base.data.as_ref()
+    }
+
+    /// Borrowing view over this item's bytes
+    pub fn view(&self) -> DataItemRef<'_> {
+        DataItemRef::new(&self.binary).expect("a parsed data item has a valid layout")
+    }
+
+    /// Address of the signer in its chain's own notation
+    pub fn owner_address(&self) -> Result<String, ArBundleErrors> {
+        let owner = self.base.raw_owner.as_ref();
+        match self.get_signature_type()? {
+            SignatureConfig::ARWEAVE => Ok(owner_to_address(owner).to_string()),
+            SignatureConfig::ED25519 | SignatureConfig::SOLANA => Ok(bs58::encode(owner).into_string()),
+            SignatureConfig::ETHEREUM => match checksum_address(owner) {
+                Some(address) => Ok(address),
+                None => Err(malformed("Ethereum owner is not an uncompressed secp256k1 public key".to_string()))
+            },
+            SignatureConfig::INJECTEDAPTOS => Ok(aptos_address(owner)),
+            SignatureConfig::MULTIAPTOS => Ok(multi_aptos_address(owner)),
+            SignatureConfig::TYPEDETHEREUM => match String::from_utf8(owner.to_vec()) {
+                Ok(address) => Ok(address),
+                Err(_) => Err(malformed("Typed Ethereum owner is not an address string".to_string()))
+            }
         }
-            
-        Err(ArBundleErrors::SignatureConfigTypeNotFound)
     }
 
-    fn get_tags_start(&self) -> usize {
-        let target_start = self.get_target_start();
-        let target_present = self.binary[target_start] == 1;
-        let mut tags_start = target_start + (if target_present { 33 } else { 1 });
-        let anchor_present = self.binary[tags_start] == 1;
-        tags_start += if anchor_present { 33 } else { 1 };
-    
-        return tags_start;
+    pub fn is_data_item(obj: Box<dyn Any>) -> bool {
+        obj.downcast_ref::<SignedDataItem>().is_some() || obj.downcast_ref::<UnsignedDataItem>().is_some()
     }
 
-    fn get_target_start(&self) -> usize {
-        return (2 + self.base.signature_length.as_ref() + self.base.owner_length.as_ref()) as usize;
+    pub fn get_signature_type(&self) -> Result<SignatureConfig, ArBundleErrors> {
+        signature_type_from_bytes(&self.binary[0..2])
     }
 
-    pub fn get_raw_id(&self) -> [u8; 32] {
-        sha256(self.base.raw_signature.as_ref())
+    pub fn get_raw_size(&self) -> usize {
+        self.binary.len()
     }
 
-    pub fn get_raw(&self) -> Vec<u8> {
-        self.binary.clone()
+    /// Bytes of the item as laid out so far, only signed items hand these out publicly
+    pub(crate) fn binary(&self) -> &[u8] {
+        &self.binary
     }
 }
 
-#[async_trait]
-impl BundleItemFn for DataItem {
-    fn sign<T: SignerMaker>(&mut self, signer: &T) -> [u8; 32] {
-        let signed = sign(self, signer);
-        self._id = Some(signed.unwrap());
+impl UnsignedDataItem {
+    pub fn from_bytes(binary: Vec<u8>) -> Result<Self, ArBundleErrors> {
+        Self::from_bytes_with_policy(binary, &TagPolicy::default())
+    }
 
-        self.get_raw_id()
+    /// Same as `from_bytes`, with tags held to `tag_policy` instead of the ANS-104 limits
+    pub fn from_bytes_with_policy(binary: Vec<u8>, tag_policy: &TagPolicy) -> Result<Self, ArBundleErrors> {
+        Self::parse(binary, tag_policy)
     }
 
-    fn is_valid<T: SignerMaker>(&self, signer: &T) -> bool {
-        self.verify(&self.binary, signer)
+    /// Writes the signature into the reserved slot right after the 2 signature type bytes
+    pub(crate) fn into_signed(mut self, signature: &[u8]) -> Result<SignedDataItem, ArBundleErrors> {
+        if signature.len() != *self.base.signature_length.as_ref() as usize {
+            return Err(ArBundleErrors::IoFailure(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Signature must be {} bytes but was {}", self.base.signature_length.as_ref(), signature.len())
+            )));
+        }
+        self.binary[2..2 + signature.len()].copy_from_slice(signature);
+        self.base.raw_signature = ResolvesTo::Item(signature.to_vec());
+        self.base.signature = ResolvesTo::Item(base64_url::encode(signature));
+
+        Ok(DataItem { base: self.base, binary: self.binary, _state: PhantomData })
+    }
+}
+
+impl SignedDataItem {
+    /// Parses a data item received from elsewhere, one whose signature slot is still zero filled is rejected
+    pub fn from_bytes(binary: Vec<u8>) -> Result<Self, ArBundleErrors> {
+        Self::from_bytes_with_policy(binary, &TagPolicy::default())
     }
 
-    fn verify<T: SignerMaker>(&self, buffer: &Vec<u8>, signer: &T) -> bool {
-        if buffer.len() < MIN_BINARY_SIZE {
-            return false;
+    /// Same as `from_bytes`, with tags held to `tag_policy` instead of the ANS-104 limits
+    pub fn from_bytes_with_policy(binary: Vec<u8>, tag_policy: &TagPolicy) -> Result<Self, ArBundleErrors> {
+        let item = Self::parse(binary, tag_policy)?;
+        if item.base.raw_signature.as_ref().iter().all(|byte| *byte == 0) {
+            return Err(ArBundleErrors::DataItemNotSigned);
         }
+        Ok(item)
+    }
 
-        let mut item = DataItem::new(buffer.clone(), signer.get_keypair_path().as_ref());
-        let _sig_type = item.get_signature_type(); // will use if diff wallets ever supported
-        let tags_start = item.get_tags_start();
+    /// Checks a serialized data item using only the owner embedded in it: the layout, the tag encoding and
+    /// the signature, with the verifier picked from the item's signature type. Structural problems are returned
+    /// as errors, a well formed item with a bad signature gives a report with `signature_valid` false.
+    pub fn verify_bytes(buffer: &[u8]) -> Result<VerificationReport, ArBundleErrors> {
+        Self::verify_bytes_with_policy(buffer, &TagPolicy::default())
+    }
 
-        let number_of_tags = byte_array_to_long(&buffer[tags_start..(tags_start + 8)].to_vec());
-        let number_of_tag_by_byte_array = buffer[tags_start + 8..tags_start + 16].to_vec();
-        let number_of_tag_bytes = byte_array_to_long(&number_of_tag_by_byte_array);
+    /// Same as `verify_bytes`, with tags held to `tag_policy` instead of the ANS-104 limits
+    pub fn verify_bytes_with_policy(buffer: &[u8], tag_policy: &TagPolicy) -> Result<VerificationReport, ArBundleErrors> {
+        let item = SignedDataItem::from_bytes_with_policy(buffer.to_vec(), tag_policy)?;
+        let tags = item.base.tags.as_ref();
 
-        if number_of_tag_bytes as usize > MAX_TAG_BYTES { return false; }
+        let signature_type = item.get_signature_type()?;
+        let verify = match get_index_to_type().get(&signature_type) {
+            Some(verify) => verify,
+            None => return Err(ArBundleErrors::SignatureConfigTypeNotFound)
+        };
+        let signature_data = get_signature_data(&item);
+        let signature_valid = verify(item.base.raw_owner.as_ref(), &signature_data, item.base.raw_signature.as_ref());
 
-        if number_of_tags > 0 {
-            let rng = (tags_start + 16)..(tags_start + 16 + (number_of_tag_bytes as usize));
-            let tags = deserialize_tags(buffer[rng].to_vec());
+        Ok(VerificationReport {
+            id: item.id(),
+            signature_type,
+            owner: item.base.owner.as_ref().clone(),
+            tag_count: tags.len(),
+            data_size: item.base.raw_data.as_ref().len(),
+            signature_valid
+        })
+    }
 
-            if tags.len() != number_of_tags as usize {
-                return false;
-            }
-        }
+    pub fn get_raw_id(&self) -> [u8; 32] {
+        sha256(self.base.raw_signature.as_ref())
+    }
+
+    pub fn id(&self) -> TxId {
+        TxId::new(self.get_raw_id())
+    }
 
-        let signature_data = get_signature_data(&mut item);
-        // todo: switch to a call capable of using other signers when desired
-        signer.verify(item.base.raw_owner.as_ref(), &signature_data.to_vec(), item.base.raw_signature.as_ref());
+    pub fn get_raw(&self) -> Vec<u8> {
+        self.binary.clone()
+    }
+}
 
-        false
+impl Serialize for SignedDataItem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("DataItem", 3)?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("binary", &self.binary)?;
+        state.serialize_field("id", &self.id())?;
+        state.end()
     }
+}
+
+#[async_trait]
+impl BundleItemFn for SignedDataItem {
+    fn is_valid(&self) -> bool {
+        self.verify(&self.binary)
+    }
+
+    fn verify(&self, buffer: &[u8]) -> bool {
+        match SignedDataItem::verify_bytes(buffer) {
+            Ok(report) => report.is_valid(),
+            Err(_) => false
+        }
+    }
+}
+
+fn malformed(message: String) -> ArBundleErrors {
+    ArBundleErrors::IoFailure(std::io::Error::new(std::io::ErrorKind::InvalidData, message))
 }
\ No newline at end of file
@@ -0,0 +1,181 @@
+use once_cell::sync::OnceCell;
+use arweave_rs::crypto::hash::sha256;
+use crate::{
+    constants::{get_sig_config, SignatureConfig},
+    data_item::{SignedDataItem, MAX_TAG_BYTES, MIN_BINARY_SIZE},
+    errors::ArBundleErrors,
+    tags::{deserialize_tags, Tag, TagPolicy},
+    types::TxId,
+    utils::byte_array_to_long
+};
+
+/// Read only view of a serialized data item that borrows its bytes, e.g. from a slice of an mmapped bundle.
+/// The layout is checked once up front, after that every field is a subslice of the borrowed bytes
+/// and nothing is copied; the id is hashed on first use and then cached.
+pub struct DataItemRef<'a> {
+    binary: &'a [u8],
+    signature_type: SignatureConfig,
+    owner_start: usize,
+    target_start: usize,
+    anchor_start: usize,
+    tags_start: usize,
+    data_start: usize,
+    tag_count: i64,
+    id: OnceCell<TxId>
+}
+
+impl<'a> DataItemRef<'a> {
+    /// Checks the layout without trusting any length in it, malformed input gives an error and never a panic
+    pub fn new(binary: &'a [u8]) -> Result<Self, ArBundleErrors> {
+        if binary.len() < MIN_BINARY_SIZE {
+            return Err(ArBundleErrors::Truncated { field: "header", offset: 0 });
+        }
+
+        let signature_type = signature_type_from_bytes(&binary[0..2])?;
+        let sig_meta = match get_sig_config().get(&signature_type) {
+            Some(sig_meta) => sig_meta,
+            None => return Err(ArBundleErrors::SignatureConfigTypeNotFound)
+        };
+        let owner_start = 2 + sig_meta.sig_length;
+        let target_start = owner_start + sig_meta.pub_length;
+        if binary.len() < owner_start {
+            return Err(ArBundleErrors::Truncated { field: "signature", offset: 2 });
+        }
+        if binary.len() < target_start {
+            return Err(ArBundleErrors::Truncated { field: "owner", offset: owner_start });
+        }
+
+        let anchor_start = target_start + optional_field_length(binary, target_start, "target")?;
+        let tags_start = anchor_start + optional_field_length(binary, anchor_start, "anchor")?;
+        if binary.len() < tags_start + 16 {
+            return Err(ArBundleErrors::Truncated { field: "tag lengths", offset: tags_start });
+        }
+
+        let tag_count = match byte_array_to_long(&binary[tags_start..tags_start + 8]) {
+            Some(tag_count) => tag_count,
+            None => return Err(ArBundleErrors::LengthOutOfRange { field: "tag count", offset: tags_start })
+        };
+        let number_of_tag_bytes = match byte_array_to_long(&binary[tags_start + 8..tags_start + 16]) {
+            Some(number_of_tag_bytes) if number_of_tag_bytes as usize <= MAX_TAG_BYTES => number_of_tag_bytes as usize,
+            _ => return Err(ArBundleErrors::LengthOutOfRange { field: "tag byte count", offset: tags_start + 8 })
+        };
+        let data_start = tags_start + 16 + number_of_tag_bytes;
+        if binary.len() < data_start {
+            return Err(ArBundleErrors::Truncated { field: "tags", offset: tags_start + 16 });
+        }
+
+        Ok(Self {
+            binary,
+            signature_type,
+            owner_start,
+            target_start,
+            anchor_start,
+            tags_start,
+            data_start,
+            tag_count,
+            id: OnceCell::new()
+        })
+    }
+
+    pub fn signature_type(&self) -> SignatureConfig {
+        self.signature_type
+    }
+
+    pub fn signature(&self) -> &'a [u8] {
+        &self.binary[2..self.owner_start]
+    }
+
+    pub fn owner(&self) -> &'a [u8] {
+        &self.binary[self.owner_start..self.target_start]
+    }
+
+    pub fn target(&self) -> Option<&'a [u8]> {
+        optional_field(self.binary, self.target_start)
+    }
+
+    pub fn anchor(&self) -> Option<&'a [u8]> {
+        optional_field(self.binary, self.anchor_start)
+    }
+
+    pub fn tag_count(&self) -> i64 {
+        self.tag_count
+    }
+
+    /// The Avro encoded tags
+    pub fn tags(&self) -> &'a [u8] {
+        &self.binary[self.tags_start + 16..self.data_start]
+    }
+
+    /// Decodes `tags` and checks them against the ANS-104 limits, this one does allocate
+    pub fn decode_tags(&self) -> Result<Vec<Tag>, ArBundleErrors> {
+        self.decode_tags_with_policy(&TagPolicy::default())
+    }
+
+    /// Same as `decode_tags`, with tags held to `tag_policy`. The decoded tags must match the declared tag count
+    pub fn decode_tags_with_policy(&self, tag_policy: &TagPolicy) -> Result<Vec<Tag>, ArBundleErrors> {
+        let tags = if self.tags().is_empty() { vec![] } else { deserialize_tags(self.tags().to_vec())? };
+        if tags.len() as i64 != self.tag_count {
+            return Err(ArBundleErrors::TagCountMismatch { declared: self.tag_count, decoded: tags.len() });
+        }
+        tag_policy.validate(&tags).map_err(ArBundleErrors::InvalidTag)?;
+        Ok(tags)
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        &self.binary[self.data_start..]
+    }
+
+    pub fn id(&self) -> TxId {
+        *self.id.get_or_init(|| TxId::new(sha256(self.signature())))
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.binary
+    }
+
+    /// Copies the viewed bytes into an owned item
+    pub fn to_data_item(&self) -> Result<SignedDataItem, ArBundleErrors> {
+        SignedDataItem::from_bytes(self.binary.to_vec())
+    }
+}
+
+pub(crate) fn signature_type_from_bytes(bytes: &[u8]) -> Result<SignatureConfig, ArBundleErrors> {
+    let signature_type_val = match byte_array_to_long(bytes) {
+        Some(signature_type_val) => signature_type_val,
+        None => return Err(ArBundleErrors::SignatureConfigTypeNotFound)
+    };
+    if SignatureConfig::ARWEAVE as i64 == signature_type_val {
+        return Ok(SignatureConfig::ARWEAVE);
+    } else if SignatureConfig::ED25519 as i64 == signature_type_val {
+        return Ok(SignatureConfig::ED25519);
+    } else if  SignatureConfig::ETHEREUM as i64 == signature_type_val {
+        return Ok(SignatureConfig::ETHEREUM);
+    } else if SignatureConfig::SOLANA as i64 == signature_type_val {
+        return Ok(SignatureConfig::SOLANA);
+    } else if SignatureConfig::INJECTEDAPTOS as i64 == signature_type_val {
+        return Ok(SignatureConfig::INJECTEDAPTOS);
+    } else if SignatureConfig::MULTIAPTOS as i64 == signature_type_val {
+        return Ok(SignatureConfig::MULTIAPTOS);
+    } else if SignatureConfig::TYPEDETHEREUM as i64 == signature_type_val {
+        return Ok(SignatureConfig::TYPEDETHEREUM);
+    }
+
+    Err(ArBundleErrors::SignatureConfigTypeNotFound)
+}
+
+/// Size of a presence byte plus the 32 byte value that follows it when the byte is 1
+fn optional_field_length(binary: &[u8], start: usize, field: &'static str) -> Result<usize, ArBundleErrors> {
+    match binary.get(start) {
+        Some(0) => Ok(1),
+        Some(1) if binary.len() >= start + 33 => Ok(33),
+        Some(1) | None => Err(ArBundleErrors::Truncated { field, offset: start }),
+        Some(value) => Err(ArBundleErrors::InvalidPresenceByte { field, value: *value })
+    }
+}
+
+fn optional_field(binary: &[u8], start: usize) -> Option<&[u8]> {
+    match binary[start] {
+        1 => Some(&binary[start + 1..start + 33]),
+        _ => None
+    }
+}
\ No newline at end of file
@@ -0,0 +1,143 @@
+use std::io::Read;
+use sha2::{Digest, Sha384};
+use tokio::io::{AsyncRead, AsyncReadExt};
+use crate::errors::ArBundleErrors;
+
+pub(crate) const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A node of the ANS-104 deep-hash tree, either raw bytes or a nested list of nodes
+pub enum DeepHashChunk<'a> {
+    Blob(&'a [u8]),
+    List(Vec<DeepHashChunk<'a>>)
+}
+
+pub fn deep_hash(chunk: &DeepHashChunk) -> [u8; 48] {
+    match chunk {
+        DeepHashChunk::Blob(blob) => {
+            let tag = [b"blob".as_slice(), blob.len().to_string().as_bytes()].concat();
+            let tagged_hash = [sha384(&tag), sha384(blob)].concat();
+            sha384(&tagged_hash)
+        },
+        DeepHashChunk::List(chunks) => {
+            let tag = [b"list".as_slice(), chunks.len().to_string().as_bytes()].concat();
+            deep_hash_chunks(chunks, sha384(&tag))
+        }
+    }
+}
+
+/// Folds each chunk's hash into the accumulator, in order
+fn deep_hash_chunks(chunks: &[DeepHashChunk], acc: [u8; 48]) -> [u8; 48] {
+    chunks.iter().fold(acc, |acc, chunk| {
+        let hash_pair = [acc, deep_hash(chunk)].concat();
+        sha384(&hash_pair)
+    })
+}
+
+/// Deep-hashes `chunks` followed by one last blob of `length` bytes read from `reader`,
+/// giving the same digest as `deep_hash` over the whole list held in memory.
+/// Exactly `length` bytes are read, anything after them is left in the reader.
+pub fn deep_hash_stream<R: Read>(chunks: &[DeepHashChunk], reader: &mut R, length: u64) -> Result<[u8; 48], ArBundleErrors> {
+    let blob_hash = deep_hash_blob_stream(reader, length)?;
+    Ok(deep_hash_list_with_blob(chunks, blob_hash))
+}
+
+pub async fn deep_hash_stream_async<R: AsyncRead + Unpin>(chunks: &[DeepHashChunk<'_>], reader: &mut R, length: u64) -> Result<[u8; 48], ArBundleErrors> {
+    let blob_hash = deep_hash_blob_stream_async(reader, length).await?;
+    Ok(deep_hash_list_with_blob(chunks, blob_hash))
+}
+
+pub fn deep_hash_blob_stream<R: Read>(reader: &mut R, length: u64) -> Result<[u8; 48], ArBundleErrors> {
+    let mut blob = DeepHashBlob::new(length);
+    let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+
+    while blob.remaining() > 0 {
+        let to_read = blob.remaining().min(STREAM_CHUNK_SIZE as u64) as usize;
+        let read = match reader.read(&mut buffer[..to_read]) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(ArBundleErrors::IoFailure(e))
+        };
+        blob.update(&buffer[..read])?;
+    }
+
+    blob.finish()
+}
+
+pub async fn deep_hash_blob_stream_async<R: AsyncRead + Unpin>(reader: &mut R, length: u64) -> Result<[u8; 48], ArBundleErrors> {
+    let mut blob = DeepHashBlob::new(length);
+    let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+
+    while blob.remaining() > 0 {
+        let to_read = blob.remaining().min(STREAM_CHUNK_SIZE as u64) as usize;
+        let read = match reader.read(&mut buffer[..to_read]).await {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) => return Err(ArBundleErrors::IoFailure(e))
+        };
+        blob.update(&buffer[..read])?;
+    }
+
+    blob.finish()
+}
+
+/// Deep hash of a single blob fed in pieces, for when the blob is produced or consumed as it is hashed
+pub struct DeepHashBlob {
+    hasher: Sha384,
+    length: u64,
+    remaining: u64
+}
+
+impl DeepHashBlob {
+    /// `length` is the size of the whole blob, it is part of the hash so it has to be known up front
+    pub fn new(length: u64) -> Self {
+        Self { hasher: Sha384::new(), length, remaining: length }
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) -> Result<(), ArBundleErrors> {
+        if bytes.len() as u64 > self.remaining {
+            return Err(stream_too_long(self.length));
+        }
+        self.hasher.update(bytes);
+        self.remaining -= bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Fails with `UnexpectedEof` if fewer than `length` bytes were fed
+    pub fn finish(self) -> Result<[u8; 48], ArBundleErrors> {
+        if self.remaining > 0 {
+            return Err(ArBundleErrors::IoFailure(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!("Stream ended after {} of {} bytes", self.length - self.remaining, self.length)
+            )));
+        }
+        let tag = [b"blob".as_slice(), self.length.to_string().as_bytes()].concat();
+        let data_hash: [u8; 48] = self.hasher.finalize().into();
+        let tagged_hash = [sha384(&tag), data_hash].concat();
+        Ok(sha384(&tagged_hash))
+    }
+}
+
+/// Deep hash of `chunks` followed by a blob hashed with `DeepHashBlob`, the same digest as `deep_hash`
+/// gives for the list with the blob appended
+pub fn deep_hash_list_with_blob(chunks: &[DeepHashChunk], blob_hash: [u8; 48]) -> [u8; 48] {
+    let tag = [b"list".as_slice(), (chunks.len() + 1).to_string().as_bytes()].concat();
+    let acc = deep_hash_chunks(chunks, sha384(&tag));
+    let hash_pair = [acc, blob_hash].concat();
+    sha384(&hash_pair)
+}
+
+pub(crate) fn stream_too_long(length: u64) -> ArBundleErrors {
+    ArBundleErrors::IoFailure(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        format!("Stream holds more than the {} bytes announced", length)
+    ))
+}
+
+pub fn sha384(message: &[u8]) -> [u8; 48] {
+    Sha384::digest(message).into()
+}
\ No newline at end of file
@@ -9,7 +9,39 @@ pub enum ArBundleErrors {
     IoFailure(std::io::Error),
     TagIsUndefinedOrEmpty,
     SignatureConfigTypeNotFound,
-    ArweaveError(arweave_rs::error::Error)
+    ArweaveError(arweave_rs::error::Error),
+    DataItemNotFound,
+    ThresholdNotMet(usize, usize),
+    InvalidSignature,
+    RemoteSignerFailed(String),
+    /// The input ends before `field`, which starts at `offset`
+    Truncated { field: &'static str, offset: usize },
+    InvalidPresenceByte { field: &'static str, value: u8 },
+    /// The length or count stored in `field` at `offset` does not fit in memory or exceeds its limit
+    LengthOutOfRange { field: &'static str, offset: usize },
+    TagCountMismatch { declared: i64, decoded: usize },
+    InvalidTagEncoding { offset: usize },
+    /// Bytes left over after the last item of a bundle
+    TrailingBytes { offset: usize },
+    DataItemNotSigned,
+    InvalidTag(TagValidationError),
+    /// The id in a bundle's header entry is not the id of the item it describes
+    BundleIdMismatch { index: usize }
+}
+
+/// Which tag broke which ANS-104 or `TagPolicy` rule, `index` is the tag's position in the item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagValidationError {
+    TooManyTags { count: usize, max: usize },
+    MissingName { index: usize },
+    MissingValue { index: usize },
+    EmptyName { index: usize },
+    EmptyValue { index: usize },
+    NameTooLong { index: usize, length: usize, max: usize },
+    ValueTooLong { index: usize, length: usize, max: usize },
+    /// The Avro encoded tags take more than `max` bytes
+    TooManyBytes { length: usize, max: usize },
+    InvalidUtf8 { index: usize }
 }
 
 impl Display for ArBundleErrors {
@@ -23,13 +55,26 @@ impl Display for ArBundleErrors {
                     None => "".to_string()
                 }
             ),
-            Self::JsonWebKeyError(e) => write!(f, "JsonWebKey error: {}", e.to_string()),
-            Self::ReadKeyPairFileFailed(e) => write!(f, "Read keypair file failed: {}", e.to_string()),
+            Self::JsonWebKeyError(e) => write!(f, "JsonWebKey error: {}", e),
+            Self::ReadKeyPairFileFailed(e) => write!(f, "Read keypair file failed: {}", e),
             Self::SignatureAttemptFailed => write!(f, "Signature attempt file failed"),
-            Self::IoFailure(e) => write!(f, "IO Failure: {}", e.to_string()),
+            Self::IoFailure(e) => write!(f, "IO Failure: {}", e),
             Self::TagIsUndefinedOrEmpty => write!(f, "Tag is undefined or empty"),
             Self::SignatureConfigTypeNotFound => write!(f, "SignatureConfig type not found"),
-            Self::ArweaveError(e) => write!(f, "Arweave client error: {}", e.to_string())
+            Self::ArweaveError(e) => write!(f, "Arweave client error: {}", e),
+            Self::DataItemNotFound => write!(f, "Data item not found in bundle"),
+            Self::ThresholdNotMet(provided, required) => write!(f, "Only {} of the {} required signatures were provided", provided, required),
+            Self::InvalidSignature => write!(f, "Signature does not match the owner"),
+            Self::RemoteSignerFailed(e) => write!(f, "Remote signer failed: {}", e),
+            Self::Truncated { field, offset } => write!(f, "Input ends before the {} at offset {}", field, offset),
+            Self::InvalidPresenceByte { field, value } => write!(f, "Invalid {} presence byte {}", field, value),
+            Self::LengthOutOfRange { field, offset } => write!(f, "The {} at offset {} is out of range", field, offset),
+            Self::TagCountMismatch { declared, decoded } => write!(f, "Data item declares {} tags but {} were decoded", declared, decoded),
+            Self::InvalidTagEncoding { offset } => write!(f, "Invalid tag encoding at offset {}", offset),
+            Self::TrailingBytes { offset } => write!(f, "Unexpected bytes after offset {}", offset),
+            Self::DataItemNotSigned => write!(f, "Data item is not signed"),
+            Self::InvalidTag(e) => write!(f, "Invalid tag: {}", e),
+            Self::BundleIdMismatch { index } => write!(f, "Bundle header id of item {} does not match the item", index)
         }
     }
 }
@@ -47,7 +92,38 @@ impl std::error::Error for ArBundleErrors {
             Self::IoFailure(e) => Some(e),
             Self::TagIsUndefinedOrEmpty => None,
             Self::SignatureConfigTypeNotFound => None,
-            Self::ArweaveError(e) => Some(e)
+            Self::ArweaveError(e) => Some(e),
+            Self::DataItemNotFound => None,
+            Self::ThresholdNotMet(_, _) => None,
+            Self::InvalidSignature => None,
+            Self::RemoteSignerFailed(_) => None,
+            Self::Truncated { .. } => None,
+            Self::InvalidPresenceByte { .. } => None,
+            Self::LengthOutOfRange { .. } => None,
+            Self::TagCountMismatch { .. } => None,
+            Self::InvalidTagEncoding { .. } => None,
+            Self::TrailingBytes { .. } => None,
+            Self::DataItemNotSigned => None,
+            Self::InvalidTag(e) => Some(e),
+            Self::BundleIdMismatch { .. } => None
+        }
+    }
+}
+
+impl Display for TagValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooManyTags { count, max } => write!(f, "{} tags, at most {} are allowed", count, max),
+            Self::MissingName { index } => write!(f, "tag {} has no name", index),
+            Self::MissingValue { index } => write!(f, "tag {} has no value", index),
+            Self::EmptyName { index } => write!(f, "tag {} has an empty name", index),
+            Self::EmptyValue { index } => write!(f, "tag {} has an empty value", index),
+            Self::NameTooLong { index, length, max } => write!(f, "name of tag {} is {} bytes, at most {} are allowed", index, length, max),
+            Self::ValueTooLong { index, length, max } => write!(f, "value of tag {} is {} bytes, at most {} are allowed", index, length, max),
+            Self::TooManyBytes { length, max } => write!(f, "tags take {} bytes, at most {} are allowed", length, max),
+            Self::InvalidUtf8 { index } => write!(f, "tag {} is not valid utf-8", index)
         }
     }
-}
\ No newline at end of file
+}
+
+impl std::error::Error for TagValidationError {}
\ No newline at end of file
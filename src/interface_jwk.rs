@@ -1,15 +1,21 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[allow(unused)]
-#[derive(Deserialize)]
+/// Arweave wallet in JSON Web Key form, every value is base64url encoded
+#[derive(Clone, Serialize, Deserialize)]
 pub struct JWKInterface {
     pub kty: String,
     pub e: String,
     pub n: String,
-    d: Option<String>,
-    p: Option<String>,
-    q: Option<String>,
-    dp: Option<String>,
-    dq: Option<String>,
-    di: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub d: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dq: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qi: Option<String>,
 }
\ No newline at end of file
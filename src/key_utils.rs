@@ -1,86 +1,173 @@
-use std::path::PathBuf;
+use std::fs;
 
-use arweave_rs::{Arweave, ArweaveBuilder};
-use arweave_rs::crypto::hash::{deep_hash, hash_all_sha256, DeepHashItem};
-use once_cell::sync::OnceCell;
+use arweave_rs::crypto::hash::{hash_all_sha256, sha256};
+use jsonwebkey as jwk;
+use rsa::{BigUint, Pss, RsaPrivateKey, RsaPublicKey};
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::traits::{PrivateKeyParts, PublicKeyParts};
+use sha2::Sha256;
 use crate::errors::ArBundleErrors;
+use crate::interface_jwk::JWKInterface;
+use crate::types::ArweaveAddress;
 
+pub const ARWEAVE_KEY_BITS: usize = 4096;
+const ARWEAVE_PUBLIC_EXPONENT: u32 = 65537;
+const SHA256_LENGTH: usize = 32;
+
+/// Signs with RSA-PSS (SHA-256, 32 byte salt) the way Arweave wallets do
 pub struct CryptoDriver {
-    arweave: Arweave
+    private_key: RsaPrivateKey
 }
 
 impl CryptoDriver {
-    pub fn new(keypair_path: &str) -> Self {
-        let mut path = PathBuf::new();
-        path.push(keypair_path);
-        Self {
-            arweave: ArweaveBuilder::new().keypair_path(path).build().unwrap()
-        }
+    pub fn new(keypair_path: &str) -> Result<Self, ArBundleErrors> {
+        Ok(Self::from_private_key(Self::from_keypair_path(keypair_path)?))
+    }
+
+    pub fn from_private_key(private_key: RsaPrivateKey) -> Self {
+        Self { private_key }
     }
 
     pub fn get_public_key(&self) -> Result<String, ArBundleErrors> {
-        match self.arweave.get_pub_key() {
-            Ok(res) => Ok(res),
-            Err(e) => Err(ArBundleErrors::ArweaveError(e))
+        Ok(base64_url::encode(&self.private_key.n().to_bytes_be()))
+    }
+
+    pub fn get_private_key(&self) -> &RsaPrivateKey {
+        &self.private_key
+    }
+
+    pub fn from_jwk(jwk: jwk::JsonWebKey) -> Result<RsaPrivateKey, ArBundleErrors> {
+        let pem = jwk.key.as_ref().to_pem();
+        Self::from_pem(&pem)
+    }
+
+    /// Reads an Arweave wallet file, the JSON form of `JWKInterface`
+    pub fn from_keypair_path(keypair_path: &str) -> Result<RsaPrivateKey, ArBundleErrors> {
+        let keypair_string = match fs::read_to_string(keypair_path) {
+            Ok(keypair_string) => keypair_string,
+            Err(e) => return Err(ArBundleErrors::ReadKeyPairFileFailed(Box::new(e)))
+        };
+        match serde_json::from_str::<JWKInterface>(&keypair_string) {
+            Ok(jwk) => Self::from_jwk_interface(&jwk),
+            Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        }
+    }
+
+    /// Builds the key straight from the wallet's components, `p` and `q` are recovered when absent
+    pub fn from_jwk_interface(jwk: &JWKInterface) -> Result<RsaPrivateKey, ArBundleErrors> {
+        let d = match &jwk.d {
+            Some(d) => d,
+            None => return Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "JWK has no private exponent"
+            )))))
+        };
+        let mut primes: Vec<BigUint> = vec![];
+        if let (Some(p), Some(q)) = (&jwk.p, &jwk.q) {
+            primes.push(Self::decode_component(p)?);
+            primes.push(Self::decode_component(q)?);
+        }
+
+        match RsaPrivateKey::from_components(
+            Self::decode_component(&jwk.n)?,
+            Self::decode_component(&jwk.e)?,
+            Self::decode_component(d)?,
+            primes
+        ) {
+            Ok(private_key) => Ok(private_key),
+            Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        }
+    }
+
+    /// Accepts PKCS#8 ("PRIVATE KEY") and PKCS#1 ("RSA PRIVATE KEY") PEM
+    pub fn from_pem(pem: &str) -> Result<RsaPrivateKey, ArBundleErrors> {
+        match RsaPrivateKey::from_pkcs8_pem(pem) {
+            Ok(private_key) => Ok(private_key),
+            Err(pkcs8_error) => match RsaPrivateKey::from_pkcs1_pem(pem) {
+                Ok(private_key) => Ok(private_key),
+                Err(_) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(pkcs8_error))))
+            }
         }
     }
 
-    // pub fn from_jwk(jwk: jwk::JsonWebKey) -> Result<RsaPrivateKey, ArBundleErrors> {
-    //     let pem = jwk.key.as_ref().to_pem();
-    //     match RsaPrivateKey::from_pkcs1_pem(&pem) {
-    //         Ok(priv_key) => Ok(priv_key),
-    //         Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
-    //     }        
-    // }
-
-    // pub fn from_keypair_path(keypair_path: &str) -> Result<RsaPrivateKey, ArBundleErrors> {
-    //     match fs::read_to_string(keypair_path) {
-    //         Ok(keypair_string) => {
-    //             match keypair_string.parse().map_err(ArBundleErrors::JsonWebKeyError) {
-    //                 Ok(jwk_parsed) => {
-    //                     match Self::from_jwk(jwk_parsed) {
-    //                         Ok(key) => Ok(key),
-    //                         Err(e) => Err(e)
-    //                     }                        
-    //                 },
-    //                 Err(e) => Err(e)
-    //             }
-    //         },
-    //         Err(e) => Err(ArBundleErrors::ReadKeyPairFileFailed(Box::new(e)))
-    //     }
-    // }
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<RsaPrivateKey, ArBundleErrors> {
+        match RsaPrivateKey::from_pkcs8_der(der) {
+            Ok(private_key) => Ok(private_key),
+            Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        }
+    }
 
     pub fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors>{
-        match self.arweave.sign(message) {
+        let hashed = sha256(message);
+        match self.private_key.sign_with_rng(&mut rand::thread_rng(), Pss::new::<Sha256>(), &hashed) {
             Ok(res) => Ok(res),
-            Err(e) => Err(ArBundleErrors::ArweaveError(e))
+            Err(_) => Err(ArBundleErrors::SignatureAttemptFailed)
         }
     }
 
-    pub fn verify(&self, pk: &[u8], message: &[u8], signature: &[u8]) -> Result<(), ArBundleErrors> {
-        match Arweave::verify(pk, message, signature) {
-            Ok(_res) => Ok(()),
-            Err(e) => Err(ArBundleErrors::ArweaveError(e))
+    /// RSA-PSS check against the raw modulus `pk`, Arweave keys always use the public exponent 65537.
+    /// Accepts the 32 byte salt `sign` uses and the maximum salt arweave-js signs with
+    pub fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> Result<(), ArBundleErrors> {
+        let public_key = match RsaPublicKey::new(BigUint::from_bytes_be(pk), BigUint::from(ARWEAVE_PUBLIC_EXPONENT)) {
+            Ok(public_key) => public_key,
+            Err(e) => return Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        };
+        let hashed = sha256(message);
+        if public_key.verify(Pss::new::<Sha256>(), &hashed, signature).is_ok() {
+            return Ok(());
+        }
+        let max_salt_length = public_key.size().saturating_sub(SHA256_LENGTH + 2);
+        match public_key.verify(Pss::new_with_salt::<Sha256>(max_salt_length), &hashed, signature) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(ArBundleErrors::InvalidSignature)
         }
     }
 
-    pub fn hash(&self, message: &[u8]) -> [u8; 32] {
+    pub fn hash(message: &[u8]) -> [u8; 32] {
         hash_all_sha256(vec![message])
     }
 
-    pub fn deep_hash(items: &Vec<u8>) -> [u8; 48] {
-        let item = DeepHashItem::from_item(items);
-        deep_hash(item)
-    }
-
     pub fn string_to_buffer(str: &str) -> &[u8] {
         str.as_bytes()
     }
+
+    fn decode_component(component: &str) -> Result<BigUint, ArBundleErrors> {
+        match base64_url::decode(component) {
+            Ok(bytes) => Ok(BigUint::from_bytes_be(&bytes)),
+            Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        }
+    }
+}
+
+/// The JWK form of a private key, with every component Arweave wallets carry
+pub fn jwk_from_private_key(private_key: &RsaPrivateKey) -> JWKInterface {
+    let encode = |value: &BigUint| base64_url::encode(&value.to_bytes_be());
+    let primes = private_key.primes();
+    let component = |value: Option<BigUint>| value.as_ref().map(encode);
+
+    JWKInterface {
+        kty: "RSA".to_string(),
+        e: encode(private_key.e()),
+        n: encode(private_key.n()),
+        d: Some(encode(private_key.d())),
+        p: primes.first().map(encode),
+        q: primes.get(1).map(encode),
+        dp: component(private_key.dp().cloned()),
+        dq: component(private_key.dq().cloned()),
+        qi: component(private_key.qinv().and_then(|qinv| qinv.to_biguint()))
+    }
+}
+
+/// Mints a new Arweave wallet, serialize it with serde_json to get the standard wallet file
+pub fn generate_arweave_jwk() -> Result<JWKInterface, ArBundleErrors> {
+    match RsaPrivateKey::new(&mut rand::thread_rng(), ARWEAVE_KEY_BITS) {
+        Ok(private_key) => Ok(jwk_from_private_key(&private_key)),
+        Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+    }
 }
 
-static CRYPTO_DRIVER: OnceCell<CryptoDriver> = OnceCell::new();
-pub fn get_crypto_driver(keypair_path: &str) -> &CryptoDriver {
-    CRYPTO_DRIVER.get_or_init(|| {
-        CryptoDriver::new(keypair_path)
-    })
+/// Arweave address of an owner (the raw RSA modulus): base64url(SHA-256(n))
+pub fn owner_to_address(owner: &[u8]) -> ArweaveAddress {
+    ArweaveAddress::new(sha256(owner))
 }
\ No newline at end of file
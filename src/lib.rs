@@ -1,17 +1,28 @@
 pub mod ar_data_base;
 pub mod ar_data_bundle;
 pub mod ar_data_create;
+pub mod bundle;
 pub mod data_item;
+pub mod data_item_ref;
 pub mod deep_hash;
 pub mod key_utils;
 pub mod errors;
 pub mod tags;
+pub mod types;
 pub mod signing {
     pub mod signer;
     pub mod chains {
         pub mod arweave_signer;
+        pub mod ed25519_signer;
+        pub mod ethereum_signer;
+        pub mod injected_aptos_signer;
+        pub mod multi_aptos_signer;
+        pub mod solana_signer;
+        pub mod typed_ethereum_signer;
     }
     pub mod constants;
+    pub mod detached_signer;
+    pub mod remote_signer;
 }
 pub mod utils;
 pub mod bundle_item;
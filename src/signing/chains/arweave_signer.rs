@@ -1,35 +1,98 @@
+use std::sync::Arc;
+use rsa::RsaPrivateKey;
+use rsa::traits::PublicKeyParts;
 use crate::errors::ArBundleErrors;
 use crate::interface_jwk::JWKInterface;
-use crate::key_utils::get_crypto_driver;
+use crate::key_utils::{owner_to_address, CryptoDriver};
 use crate::constants::{get_sig_config, SignatureConfig};
-use crate::signing::signer::SignerMaker;
+use crate::signing::constants::IndexToTypeValueFn;
+use crate::signing::signer::Signer;
+use crate::types::ArweaveAddress;
 
-#[allow(unused)]
+/// Owns the key material of one wallet, loaded once when the signer is created.
+/// Clones share the loaded key, so a signer can be handed to several threads cheaply.
+#[derive(Clone)]
 pub struct ArweaveSigner {
-    signature_type: i64,
+    signature_type: SignatureConfig,
     owner_length: usize,
     signature_length: usize,
-    jwk: JWKInterface,
-    pub pk: String,
-    keypair_path: String
+    pk: String,
+    keypair_path: String,
+    crypto_driver: Arc<CryptoDriver>
 }
 
 impl ArweaveSigner {
-    pub fn new(jwk: JWKInterface, keypair_path: &str) -> Self {
+    /// `jwk` must be the wallet stored at `keypair_path`, a key whose modulus differs is rejected
+    pub fn new(jwk: JWKInterface, keypair_path: &str) -> Result<Self, ArBundleErrors> {
+        let crypto_driver = CryptoDriver::new(keypair_path)?;
+        if base64_url::decode(&jwk.n).ok() != Some(crypto_driver.get_private_key().n().to_bytes_be()) {
+            return Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("JWK does not match the wallet at {}", keypair_path)
+            )))));
+        }
+        Ok(Self::from_crypto_driver(keypair_path, crypto_driver))
+    }
+
+    pub fn from_keypair_path(keypair_path: &str) -> Result<Self, ArBundleErrors> {
+        Ok(Self::from_crypto_driver(keypair_path, CryptoDriver::new(keypair_path)?))
+    }
+
+    /// Loads a wallet from its JSON, e.g. as handed out by a secrets manager
+    pub fn from_jwk_str(jwk: &str) -> Result<Self, ArBundleErrors> {
+        match serde_json::from_str::<JWKInterface>(jwk) {
+            Ok(jwk) => Self::from_jwk(jwk),
+            Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        }
+    }
+
+    pub fn from_jwk(jwk: JWKInterface) -> Result<Self, ArBundleErrors> {
+        let private_key = CryptoDriver::from_jwk_interface(&jwk)?;
+        Ok(Self::from_crypto_driver("", CryptoDriver::from_private_key(private_key)))
+    }
+
+    /// Accepts PKCS#8 or PKCS#1 PEM
+    pub fn from_pem(pem: &str) -> Result<Self, ArBundleErrors> {
+        Self::from_private_key(CryptoDriver::from_pem(pem)?)
+    }
+
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, ArBundleErrors> {
+        Self::from_private_key(CryptoDriver::from_pkcs8_der(der)?)
+    }
+
+    /// The modulus base64url encoded, the `n` of the wallet's JWK
+    pub fn public_key(&self) -> &str {
+        &self.pk
+    }
+
+    pub fn address(&self) -> ArweaveAddress {
+        owner_to_address(&self.get_public_key())
+    }
+
+    /// Empty when the key was not loaded from a file
+    pub fn get_keypair_path(&self) -> String {
+        self.keypair_path.clone()
+    }
+
+    fn from_private_key(private_key: RsaPrivateKey) -> Result<Self, ArBundleErrors> {
+        Ok(Self::from_crypto_driver("", CryptoDriver::from_private_key(private_key)))
+    }
+
+    fn from_crypto_driver(keypair_path: &str, crypto_driver: CryptoDriver) -> Self {
         let sig_config = get_sig_config();
         Self {
-            signature_type: 1,
+            signature_type: SignatureConfig::ARWEAVE,
             owner_length: sig_config.get(&SignatureConfig::ARWEAVE).unwrap().pub_length,
             signature_length: sig_config.get(&SignatureConfig::ARWEAVE).unwrap().sig_length,
-            pk: jwk.n.clone(),
-            jwk,            
-            keypair_path: keypair_path.to_string()
+            pk: base64_url::encode(&crypto_driver.get_private_key().n().to_bytes_be()),
+            keypair_path: keypair_path.to_string(),
+            crypto_driver: Arc::new(crypto_driver)
         }
-    }   
+    }
 }
 
-impl SignerMaker for ArweaveSigner {
-    fn get_signature_type(&self) -> i64 {
+impl Signer for ArweaveSigner {
+    fn signature_type(&self) -> SignatureConfig {
         self.signature_type
     }
 
@@ -42,27 +105,20 @@ impl SignerMaker for ArweaveSigner {
     }
 
     fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
-        match get_crypto_driver(self.keypair_path.as_str()).sign(message) {
+        match self.crypto_driver.sign(message) {
             Ok(res) => Ok(res),
             Err(e) => Err(e)
         }
     }
 
+    /// The raw modulus, `pk` holds it base64url encoded
     fn get_public_key(&self) -> Vec<u8> {
-        let mut output: Vec<u8> = vec![];
-        base64_url::encode_to_vec(&self.pk, &mut output);
-        output
-    }
-
-    /// todo: figure out what to do about keypair_path
-    fn verify(&self, pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
-        match get_crypto_driver(self.keypair_path.as_str()).verify(pk, message, signature) {
-            Ok(()) => true,
-            Err(_) => false
-        }
+        base64_url::decode(&self.pk).unwrap_or_default()
     }
+}
 
-    fn get_keypair_path(&self) -> String {
-        self.keypair_path.clone()
+impl IndexToTypeValueFn for ArweaveSigner {
+    fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        CryptoDriver::verify(pk, message, signature).is_ok()
     }
 }
\ No newline at end of file
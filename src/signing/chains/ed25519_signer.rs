@@ -0,0 +1,96 @@
+use ed25519_dalek::{Signature, Signer as _, SigningKey, Verifier, VerifyingKey, KEYPAIR_LENGTH, SECRET_KEY_LENGTH};
+use crate::errors::ArBundleErrors;
+use crate::constants::{get_sig_config, SignatureConfig};
+use crate::signing::constants::IndexToTypeValueFn;
+use crate::signing::signer::Signer;
+
+pub struct Ed25519Signer {
+    signature_type: SignatureConfig,
+    owner_length: usize,
+    signature_length: usize,
+    signing_key: SigningKey
+}
+
+impl Ed25519Signer {
+    /// Accepts either a 32 byte seed or a 64 byte keypair (seed followed by public key)
+    pub fn new(key: &[u8]) -> Result<Self, ArBundleErrors> {
+        match key.len() {
+            SECRET_KEY_LENGTH => Self::from_seed(key),
+            KEYPAIR_LENGTH => Self::from_keypair(key),
+            len => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Ed25519 key must be {} or {} bytes but was {}", SECRET_KEY_LENGTH, KEYPAIR_LENGTH, len)
+            )))))
+        }
+    }
+
+    pub fn from_seed(seed: &[u8]) -> Result<Self, ArBundleErrors> {
+        let seed: &[u8; SECRET_KEY_LENGTH] = match seed.try_into() {
+            Ok(seed) => seed,
+            Err(e) => return Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        };
+        Ok(Self::from_signing_key(SigningKey::from_bytes(seed)))
+    }
+
+    /// The keypair's public half must match the one derived from its seed
+    pub fn from_keypair(keypair: &[u8]) -> Result<Self, ArBundleErrors> {
+        let keypair: &[u8; KEYPAIR_LENGTH] = match keypair.try_into() {
+            Ok(keypair) => keypair,
+            Err(e) => return Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        };
+        match SigningKey::from_keypair_bytes(keypair) {
+            Ok(signing_key) => Ok(Self::from_signing_key(signing_key)),
+            Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        }
+    }
+
+    fn from_signing_key(signing_key: SigningKey) -> Self {
+        let sig_config = get_sig_config();
+        Self {
+            signature_type: SignatureConfig::ED25519,
+            owner_length: sig_config.get(&SignatureConfig::ED25519).unwrap().pub_length,
+            signature_length: sig_config.get(&SignatureConfig::ED25519).unwrap().sig_length,
+            signing_key
+        }
+    }
+}
+
+impl Signer for Ed25519Signer {
+    fn signature_type(&self) -> SignatureConfig {
+        self.signature_type
+    }
+
+    fn get_signature_length(&self) -> usize {
+        self.signature_length
+    }
+
+    fn get_owner_length(&self) -> usize {
+        self.owner_length
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        Ok(self.signing_key.sign(message).to_bytes().to_vec())
+    }
+
+    fn get_public_key(&self) -> Vec<u8> {
+        self.signing_key.verifying_key().to_bytes().to_vec()
+    }
+}
+
+impl IndexToTypeValueFn for Ed25519Signer {
+    fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        verify_ed25519(pk, message, signature)
+    }
+}
+
+pub(crate) fn verify_ed25519(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let pk: &[u8; 32] = match pk.try_into() {
+        Ok(pk) => pk,
+        Err(_) => return false
+    };
+    let (verifying_key, signature) = match (VerifyingKey::from_bytes(pk), Signature::from_slice(signature)) {
+        (Ok(verifying_key), Ok(signature)) => (verifying_key, signature),
+        _ => return false
+    };
+    verifying_key.verify(message, &signature).is_ok()
+}
\ No newline at end of file
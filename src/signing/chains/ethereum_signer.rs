@@ -0,0 +1,152 @@
+use k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+use sha3::{Digest, Keccak256};
+use crate::errors::ArBundleErrors;
+use crate::constants::{get_sig_config, SignatureConfig};
+use crate::signing::constants::IndexToTypeValueFn;
+use crate::signing::signer::Signer;
+
+const PERSONAL_MESSAGE_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+
+pub struct EthereumSigner {
+    signature_type: SignatureConfig,
+    owner_length: usize,
+    signature_length: usize,
+    signing_key: SigningKey
+}
+
+impl EthereumSigner {
+    pub fn new(private_key: &[u8]) -> Result<Self, ArBundleErrors> {
+        let sig_config = get_sig_config();
+        Ok(Self {
+            signature_type: SignatureConfig::ETHEREUM,
+            owner_length: sig_config.get(&SignatureConfig::ETHEREUM).unwrap().pub_length,
+            signature_length: sig_config.get(&SignatureConfig::ETHEREUM).unwrap().sig_length,
+            signing_key: signing_key_from_bytes(private_key)?
+        })
+    }
+
+    /// Accepts the hex private key format wallets export, with or without the 0x prefix
+    pub fn from_hex(private_key: &str) -> Result<Self, ArBundleErrors> {
+        Self::new(&private_key_from_hex(private_key)?)
+    }
+
+    /// Checksummed 0x address
+    pub fn address(&self) -> String {
+        checksum_address(&self.get_public_key()).unwrap_or_default()
+    }
+}
+
+impl Signer for EthereumSigner {
+    fn signature_type(&self) -> SignatureConfig {
+        self.signature_type
+    }
+
+    fn get_signature_length(&self) -> usize {
+        self.signature_length
+    }
+
+    fn get_owner_length(&self) -> usize {
+        self.owner_length
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        sign_prehash(&self.signing_key, &hash_personal_message(message))
+    }
+
+    fn get_public_key(&self) -> Vec<u8> {
+        uncompressed_public_key(self.signing_key.verifying_key())
+    }
+}
+
+impl IndexToTypeValueFn for EthereumSigner {
+    fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        verify_personal_message(pk, message, signature)
+    }
+}
+
+pub(crate) fn verify_personal_message(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    match recover_verifying_key(&hash_personal_message(message), signature) {
+        Some(verifying_key) => uncompressed_public_key(&verifying_key) == pk,
+        None => false
+    }
+}
+
+pub(crate) fn signing_key_from_bytes(private_key: &[u8]) -> Result<SigningKey, ArBundleErrors> {
+    match SigningKey::from_slice(private_key) {
+        Ok(signing_key) => Ok(signing_key),
+        Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+    }
+}
+
+pub(crate) fn private_key_from_hex(private_key: &str) -> Result<Vec<u8>, ArBundleErrors> {
+    let private_key = private_key.strip_prefix("0x").unwrap_or(private_key);
+    match hex::decode(private_key) {
+        Ok(private_key) => Ok(private_key),
+        Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+    }
+}
+
+pub(crate) fn keccak256(message: &[u8]) -> [u8; 32] {
+    Keccak256::digest(message).into()
+}
+
+/// EIP-191 personal_sign digest, as produced by ethers' `signMessage` for byte messages
+pub(crate) fn hash_personal_message(message: &[u8]) -> [u8; 32] {
+    let prefix = format!("{}{}", PERSONAL_MESSAGE_PREFIX, message.len());
+    keccak256(&[prefix.as_bytes(), message].concat())
+}
+
+/// Signs a 32 byte digest, returning r || s || v with v in the 27/28 form Ethereum wallets use
+pub(crate) fn sign_prehash(signing_key: &SigningKey, prehash: &[u8; 32]) -> Result<Vec<u8>, ArBundleErrors> {
+    let (signature, recovery_id) = match signing_key.sign_prehash_recoverable(prehash) {
+        Ok(res) => res,
+        Err(_) => return Err(ArBundleErrors::SignatureAttemptFailed)
+    };
+    let mut output = signature.to_bytes().to_vec();
+    output.push(27 + recovery_id.to_byte());
+    Ok(output)
+}
+
+pub(crate) fn recover_verifying_key(prehash: &[u8; 32], signature: &[u8]) -> Option<VerifyingKey> {
+    if signature.len() != 65 {
+        return None;
+    }
+    let v = signature[64];
+    let recovery_id = RecoveryId::from_byte(if v >= 27 { v - 27 } else { v })?;
+    let signature = Signature::from_slice(&signature[..64]).ok()?;
+    VerifyingKey::recover_from_prehash(prehash, &signature, recovery_id).ok()
+}
+
+/// 65 byte SEC1 encoding, starting with the 0x04 tag
+pub(crate) fn uncompressed_public_key(verifying_key: &VerifyingKey) -> Vec<u8> {
+    verifying_key.to_encoded_point(false).as_bytes().to_vec()
+}
+
+/// Last 20 bytes of the Keccak-256 of the uncompressed public key, without its 0x04 tag
+pub(crate) fn compute_address(pk: &[u8]) -> Option<[u8; 20]> {
+    if pk.len() != 65 || pk[0] != 4 {
+        return None;
+    }
+    let hash = keccak256(&pk[1..]);
+    hash[12..].try_into().ok()
+}
+
+/// Checksummed 0x address of a 65 byte uncompressed public key
+pub(crate) fn checksum_address(pk: &[u8]) -> Option<String> {
+    compute_address(pk).map(|address| to_checksum_address(&address))
+}
+
+/// EIP-55 mixed case 0x address
+pub(crate) fn to_checksum_address(address: &[u8; 20]) -> String {
+    let lower = hex::encode(address);
+    let hash = keccak256(lower.as_bytes());
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 { c.to_ascii_uppercase() } else { c }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
\ No newline at end of file
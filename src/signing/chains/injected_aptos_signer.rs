@@ -0,0 +1,77 @@
+use sha3::{Digest, Sha3_256};
+use crate::errors::ArBundleErrors;
+use crate::constants::{get_sig_config, SignatureConfig};
+use crate::signing::chains::ed25519_signer::{verify_ed25519, Ed25519Signer};
+use crate::signing::constants::IndexToTypeValueFn;
+use crate::signing::signer::Signer;
+
+const APTOS_NONCE: &str = "bundlr";
+const ED25519_SCHEME: u8 = 0;
+
+/// Signs the way Aptos browser wallets do through `signMessage`: the ed25519 signature covers
+/// an "APTOS" envelope holding the hex encoded message and a fixed nonce, not the message itself
+pub struct InjectedAptosSigner {
+    signature_type: SignatureConfig,
+    owner_length: usize,
+    signature_length: usize,
+    signer: Ed25519Signer
+}
+
+impl InjectedAptosSigner {
+    /// Accepts a 32 byte seed or a 64 byte keypair
+    pub fn new(key: &[u8]) -> Result<Self, ArBundleErrors> {
+        let sig_config = get_sig_config();
+        Ok(Self {
+            signature_type: SignatureConfig::INJECTEDAPTOS,
+            owner_length: sig_config.get(&SignatureConfig::INJECTEDAPTOS).unwrap().pub_length,
+            signature_length: sig_config.get(&SignatureConfig::INJECTEDAPTOS).unwrap().sig_length,
+            signer: Ed25519Signer::new(key)?
+        })
+    }
+
+    pub fn address(&self) -> String {
+        aptos_address(&self.signer.get_public_key())
+    }
+}
+
+impl Signer for InjectedAptosSigner {
+    fn signature_type(&self) -> SignatureConfig {
+        self.signature_type
+    }
+
+    fn get_signature_length(&self) -> usize {
+        self.signature_length
+    }
+
+    fn get_owner_length(&self) -> usize {
+        self.owner_length
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        self.signer.sign(&aptos_signing_message(message))
+    }
+
+    fn get_public_key(&self) -> Vec<u8> {
+        self.signer.get_public_key()
+    }
+}
+
+impl IndexToTypeValueFn for InjectedAptosSigner {
+    fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        verify_injected_aptos(pk, message, signature)
+    }
+}
+
+pub(crate) fn verify_injected_aptos(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    verify_ed25519(pk, &aptos_signing_message(message), signature)
+}
+
+pub(crate) fn aptos_signing_message(message: &[u8]) -> Vec<u8> {
+    format!("APTOS\nmessage: {}\nnonce: {}", hex::encode(message), APTOS_NONCE).into_bytes()
+}
+
+/// Account address of a single key account, its authentication key: SHA3-256(public key || scheme)
+pub(crate) fn aptos_address(pk: &[u8]) -> String {
+    let auth_key = Sha3_256::digest([pk, &[ED25519_SCHEME]].concat());
+    format!("0x{}", hex::encode(auth_key))
+}
\ No newline at end of file
@@ -0,0 +1,219 @@
+use sha3::{Digest, Sha3_256};
+use crate::errors::ArBundleErrors;
+use crate::constants::{get_sig_config, SignatureConfig};
+use crate::signing::chains::ed25519_signer::{verify_ed25519, Ed25519Signer};
+use crate::signing::constants::IndexToTypeValueFn;
+use crate::signing::signer::Signer;
+
+pub const MAX_MULTI_APTOS_KEYS: usize = 32;
+const PUBLIC_KEY_LENGTH: usize = 32;
+const SIGNATURE_LENGTH: usize = 64;
+const BITMAP_LENGTH: usize = 4;
+const MULTI_ED25519_SCHEME: u8 = 1;
+
+/// Aptos MultiEd25519 account. The owner is 32 public key slots followed by the threshold byte,
+/// the signature is 32 signature slots followed by a 4 byte bitmap of the slots that are filled.
+/// A signature always sits in the slot of the key that produced it.
+pub struct MultiAptosSigner {
+    signature_type: SignatureConfig,
+    owner_length: usize,
+    signature_length: usize,
+    public_keys: Vec<[u8; 32]>,
+    threshold: u8,
+    signers: Vec<(usize, Ed25519Signer)>
+}
+
+#[derive(Default)]
+pub struct MultiAptosSignerBuilder {
+    public_keys: Vec<[u8; 32]>,
+    threshold: Option<u8>,
+    signing_keys: Vec<Vec<u8>>
+}
+
+impl MultiAptosSignerBuilder {
+    /// Adds the next key of the account, keys keep the order they are added in
+    pub fn public_key(mut self, public_key: [u8; 32]) -> Self {
+        self.public_keys.push(public_key);
+        self
+    }
+
+    pub fn threshold(mut self, threshold: u8) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Adds a private key (32 byte seed or 64 byte keypair) held in this process,
+    /// its public key must be one of the account's keys
+    pub fn signing_key(mut self, key: &[u8]) -> Self {
+        self.signing_keys.push(key.to_vec());
+        self
+    }
+
+    pub fn build(self) -> Result<MultiAptosSigner, ArBundleErrors> {
+        if self.public_keys.is_empty() || self.public_keys.len() > MAX_MULTI_APTOS_KEYS {
+            return Err(invalid_account(format!("MultiAptos accounts need 1 to {} keys but {} were given", MAX_MULTI_APTOS_KEYS, self.public_keys.len())));
+        }
+        let threshold = match self.threshold {
+            Some(threshold) if threshold > 0 && threshold as usize <= self.public_keys.len() => threshold,
+            Some(threshold) => return Err(invalid_account(format!("Threshold {} is not satisfiable by {} keys", threshold, self.public_keys.len()))),
+            None => return Err(invalid_account("MultiAptos threshold was not set".to_string()))
+        };
+
+        let mut signers: Vec<(usize, Ed25519Signer)> = Vec::with_capacity(self.signing_keys.len());
+        for key in self.signing_keys.iter() {
+            let signer = Ed25519Signer::new(key)?;
+            let public_key = signer.get_public_key();
+            match self.public_keys.iter().position(|pk| pk.as_slice() == public_key.as_slice()) {
+                Some(index) => signers.push((index, signer)),
+                None => return Err(invalid_account(format!("Signing key {} is not part of the account", base64_url::encode(&public_key))))
+            }
+        }
+        signers.sort_by_key(|(index, _)| *index);
+
+        let sig_config = get_sig_config();
+        Ok(MultiAptosSigner {
+            signature_type: SignatureConfig::MULTIAPTOS,
+            owner_length: sig_config.get(&SignatureConfig::MULTIAPTOS).unwrap().pub_length,
+            signature_length: sig_config.get(&SignatureConfig::MULTIAPTOS).unwrap().sig_length,
+            public_keys: self.public_keys,
+            threshold,
+            signers
+        })
+    }
+}
+
+impl MultiAptosSigner {
+    pub fn builder() -> MultiAptosSignerBuilder {
+        MultiAptosSignerBuilder::default()
+    }
+
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    pub fn address(&self) -> String {
+        multi_aptos_address(&self.get_public_key())
+    }
+
+    /// Packs partial signatures collected elsewhere, as `(key index, signature)` pairs, into a MultiAptos signature.
+    /// Every partial signature must be valid for its key and there must be at least `threshold` of them.
+    pub fn combine_signatures(&self, message: &[u8], partial_signatures: &[(usize, Vec<u8>)]) -> Result<Vec<u8>, ArBundleErrors> {
+        for (index, signature) in partial_signatures.iter() {
+            let valid = match self.public_keys.get(*index) {
+                Some(public_key) => verify_ed25519(public_key, message, signature),
+                None => false
+            };
+            if !valid {
+                return Err(ArBundleErrors::SignatureAttemptFailed);
+            }
+        }
+        if partial_signatures.len() < self.threshold as usize {
+            return Err(ArBundleErrors::ThresholdNotMet(partial_signatures.len(), self.threshold as usize));
+        }
+
+        pack_multi_aptos_signature(partial_signatures)
+    }
+}
+
+impl Signer for MultiAptosSigner {
+    fn signature_type(&self) -> SignatureConfig {
+        self.signature_type
+    }
+
+    fn get_signature_length(&self) -> usize {
+        self.signature_length
+    }
+
+    fn get_owner_length(&self) -> usize {
+        self.owner_length
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        let mut partial_signatures: Vec<(usize, Vec<u8>)> = Vec::with_capacity(self.signers.len());
+        for (index, signer) in self.signers.iter() {
+            partial_signatures.push((*index, signer.sign(message)?));
+        }
+        self.combine_signatures(message, &partial_signatures)
+    }
+
+    fn get_public_key(&self) -> Vec<u8> {
+        let mut owner = vec![0u8; self.owner_length];
+        for (index, public_key) in self.public_keys.iter().enumerate() {
+            owner[index * PUBLIC_KEY_LENGTH..(index + 1) * PUBLIC_KEY_LENGTH].copy_from_slice(public_key);
+        }
+        owner[self.owner_length - 1] = self.threshold;
+        owner
+    }
+}
+
+impl IndexToTypeValueFn for MultiAptosSigner {
+    fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        verify_multi_aptos(pk, message, signature)
+    }
+}
+
+/// Places each `(key index, signature)` pair in its slot and sets the matching bitmap bit,
+/// bits are read from the most significant bit of the first byte
+pub fn pack_multi_aptos_signature(partial_signatures: &[(usize, Vec<u8>)]) -> Result<Vec<u8>, ArBundleErrors> {
+    let signature_length = get_sig_config().get(&SignatureConfig::MULTIAPTOS).unwrap().sig_length;
+    let bitmap_start = signature_length - BITMAP_LENGTH;
+    let mut packed = vec![0u8; signature_length];
+
+    for (index, signature) in partial_signatures.iter() {
+        if *index >= MAX_MULTI_APTOS_KEYS || signature.len() != SIGNATURE_LENGTH {
+            return Err(ArBundleErrors::SignatureAttemptFailed);
+        }
+        let bit = 128u8 >> (index % 8);
+        if packed[bitmap_start + index / 8] & bit != 0 {
+            return Err(ArBundleErrors::SignatureAttemptFailed);
+        }
+        packed[bitmap_start + index / 8] |= bit;
+        packed[index * SIGNATURE_LENGTH..(index + 1) * SIGNATURE_LENGTH].copy_from_slice(signature);
+    }
+
+    Ok(packed)
+}
+
+pub(crate) fn verify_multi_aptos(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let sig_meta = get_sig_config().get(&SignatureConfig::MULTIAPTOS).unwrap();
+    if pk.len() != sig_meta.pub_length || signature.len() != sig_meta.sig_length {
+        return false;
+    }
+    let threshold = pk[sig_meta.pub_length - 1] as usize;
+    let bitmap = &signature[sig_meta.sig_length - BITMAP_LENGTH..];
+
+    let mut signature_count = 0;
+    for index in 0..MAX_MULTI_APTOS_KEYS {
+        if bitmap[index / 8] & (128u8 >> (index % 8)) == 0 {
+            continue;
+        }
+        let public_key = &pk[index * PUBLIC_KEY_LENGTH..(index + 1) * PUBLIC_KEY_LENGTH];
+        let partial_signature = &signature[index * SIGNATURE_LENGTH..(index + 1) * SIGNATURE_LENGTH];
+        if !verify_ed25519(public_key, message, partial_signature) {
+            return false;
+        }
+        signature_count += 1;
+    }
+
+    threshold > 0 && signature_count >= threshold
+}
+
+/// Account address of a MultiEd25519 account: SHA3-256(keys || threshold || scheme), where the
+/// zero filled key slots past the account's last key are left out
+pub(crate) fn multi_aptos_address(owner: &[u8]) -> String {
+    let threshold_position = owner.len().saturating_sub(1);
+    let public_keys: Vec<u8> = owner[..threshold_position]
+        .chunks(PUBLIC_KEY_LENGTH)
+        .take_while(|public_key| public_key.iter().any(|byte| *byte != 0))
+        .flatten()
+        .copied()
+        .collect();
+    let threshold = owner.get(threshold_position).copied().unwrap_or(0);
+
+    let auth_key = Sha3_256::digest([public_keys.as_slice(), &[threshold, MULTI_ED25519_SCHEME]].concat());
+    format!("0x{}", hex::encode(auth_key))
+}
+
+fn invalid_account(message: String) -> ArBundleErrors {
+    ArBundleErrors::KeyCreationFailed(Some(Box::new(std::io::Error::new(std::io::ErrorKind::InvalidInput, message))))
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use std::fs;
+use crate::errors::ArBundleErrors;
+use crate::constants::{get_sig_config, SignatureConfig};
+use crate::signing::chains::ed25519_signer::{verify_ed25519, Ed25519Signer};
+use crate::signing::constants::IndexToTypeValueFn;
+use crate::signing::signer::Signer;
+
+/// Solana keys are plain ed25519 keys, only the signature type and address notation differ
+pub struct SolanaSigner {
+    signature_type: SignatureConfig,
+    owner_length: usize,
+    signature_length: usize,
+    signer: Ed25519Signer
+}
+
+impl SolanaSigner {
+    /// Accepts a 64 byte keypair, as found in `id.json`, or its 32 byte seed
+    pub fn new(keypair: &[u8]) -> Result<Self, ArBundleErrors> {
+        let sig_config = get_sig_config();
+        Ok(Self {
+            signature_type: SignatureConfig::SOLANA,
+            owner_length: sig_config.get(&SignatureConfig::SOLANA).unwrap().pub_length,
+            signature_length: sig_config.get(&SignatureConfig::SOLANA).unwrap().sig_length,
+            signer: Ed25519Signer::new(keypair)?
+        })
+    }
+
+    /// Loads the JSON byte array keypair format written by `solana-keygen`
+    pub fn from_keypair_file(keypair_path: &str) -> Result<Self, ArBundleErrors> {
+        match fs::read_to_string(keypair_path) {
+            Ok(keypair_json) => Self::from_keypair_json(&keypair_json),
+            Err(e) => Err(ArBundleErrors::ReadKeyPairFileFailed(Box::new(e)))
+        }
+    }
+
+    pub fn from_keypair_json(keypair_json: &str) -> Result<Self, ArBundleErrors> {
+        match serde_json::from_str::<Vec<u8>>(keypair_json) {
+            Ok(keypair) => Self::new(&keypair),
+            Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        }
+    }
+
+    /// Loads a base58 secret key, as exported by browser wallets
+    pub fn from_base58(secret_key: &str) -> Result<Self, ArBundleErrors> {
+        match bs58::decode(secret_key.trim()).into_vec() {
+            Ok(keypair) => Self::new(&keypair),
+            Err(e) => Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(e))))
+        }
+    }
+
+    pub fn address(&self) -> String {
+        bs58::encode(self.signer.get_public_key()).into_string()
+    }
+}
+
+impl Signer for SolanaSigner {
+    fn signature_type(&self) -> SignatureConfig {
+        self.signature_type
+    }
+
+    fn get_signature_length(&self) -> usize {
+        self.signature_length
+    }
+
+    fn get_owner_length(&self) -> usize {
+        self.owner_length
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        self.signer.sign(message)
+    }
+
+    fn get_public_key(&self) -> Vec<u8> {
+        self.signer.get_public_key()
+    }
+}
+
+impl IndexToTypeValueFn for SolanaSigner {
+    fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        verify_ed25519(pk, message, signature)
+    }
+}
\ No newline at end of file
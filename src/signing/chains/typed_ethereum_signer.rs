@@ -0,0 +1,118 @@
+use k256::ecdsa::SigningKey;
+use crate::errors::ArBundleErrors;
+use crate::constants::{get_sig_config, SignatureConfig};
+use crate::signing::chains::ethereum_signer::{
+    checksum_address, compute_address, keccak256, private_key_from_hex, recover_verifying_key, sign_prehash,
+    signing_key_from_bytes, uncompressed_public_key
+};
+use crate::signing::constants::IndexToTypeValueFn;
+use crate::signing::signer::Signer;
+
+const DOMAIN_TYPE: &str = "EIP712Domain(string name,string version)";
+const DOMAIN_NAME: &str = "Bundlr";
+const DOMAIN_VERSION: &str = "1";
+const MESSAGE_TYPE: &str = "Bundlr(bytes Transaction hash,address address)";
+
+/// Signs the deep hash as EIP-712 typed data, for wallets that only expose `eth_signTypedData`.
+/// The owner is the checksummed 0x address as ASCII rather than a public key.
+pub struct TypedEthereumSigner {
+    signature_type: SignatureConfig,
+    owner_length: usize,
+    signature_length: usize,
+    signing_key: SigningKey,
+    address: String
+}
+
+impl TypedEthereumSigner {
+    pub fn new(private_key: &[u8]) -> Result<Self, ArBundleErrors> {
+        let signing_key = signing_key_from_bytes(private_key)?;
+        let address = match checksum_address(&uncompressed_public_key(signing_key.verifying_key())) {
+            Some(address) => address,
+            None => return Err(ArBundleErrors::KeyCreationFailed(None))
+        };
+        let sig_config = get_sig_config();
+        Ok(Self {
+            signature_type: SignatureConfig::TYPEDETHEREUM,
+            owner_length: sig_config.get(&SignatureConfig::TYPEDETHEREUM).unwrap().pub_length,
+            signature_length: sig_config.get(&SignatureConfig::TYPEDETHEREUM).unwrap().sig_length,
+            signing_key,
+            address
+        })
+    }
+
+    /// Same key format as `EthereumSigner::from_hex`
+    pub fn from_hex(private_key: &str) -> Result<Self, ArBundleErrors> {
+        Self::new(&private_key_from_hex(private_key)?)
+    }
+
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+}
+
+impl Signer for TypedEthereumSigner {
+    fn signature_type(&self) -> SignatureConfig {
+        self.signature_type
+    }
+
+    fn get_signature_length(&self) -> usize {
+        self.signature_length
+    }
+
+    fn get_owner_length(&self) -> usize {
+        self.owner_length
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        let address = match parse_address(&self.address) {
+            Some(address) => address,
+            None => return Err(ArBundleErrors::SignatureAttemptFailed)
+        };
+        sign_prehash(&self.signing_key, &hash_typed_data(&address, message))
+    }
+
+    fn get_public_key(&self) -> Vec<u8> {
+        self.address.as_bytes().to_vec()
+    }
+}
+
+impl IndexToTypeValueFn for TypedEthereumSigner {
+    fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        verify_typed_data(pk, message, signature)
+    }
+}
+
+pub(crate) fn verify_typed_data(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let address = match std::str::from_utf8(pk).ok().and_then(parse_address) {
+        Some(address) => address,
+        None => return false
+    };
+    match recover_verifying_key(&hash_typed_data(&address, message), signature) {
+        Some(verifying_key) => compute_address(&uncompressed_public_key(&verifying_key)) == Some(address),
+        None => false
+    }
+}
+
+/// keccak256(0x1901 || domainSeparator || hashStruct({ "Transaction hash": message, address }))
+fn hash_typed_data(address: &[u8; 20], message: &[u8]) -> [u8; 32] {
+    let domain_separator = keccak256(&[
+        keccak256(DOMAIN_TYPE.as_bytes()),
+        keccak256(DOMAIN_NAME.as_bytes()),
+        keccak256(DOMAIN_VERSION.as_bytes())
+    ].concat());
+
+    let mut encoded_address = [0u8; 32];
+    encoded_address[12..].copy_from_slice(address);
+    let struct_hash = keccak256(&[
+        keccak256(MESSAGE_TYPE.as_bytes()),
+        keccak256(message),
+        encoded_address
+    ].concat());
+
+    keccak256(&[[0x19u8, 0x01].as_slice(), &domain_separator, &struct_hash].concat())
+}
+
+fn parse_address(address: &str) -> Option<[u8; 20]> {
+    let address = address.strip_prefix("0x")?;
+    hex::decode(address).ok()?.try_into().ok()
+}
\ No newline at end of file
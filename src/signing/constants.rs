@@ -1,25 +1,37 @@
-use crate::{interface_jwk::JWKInterface, signing::chains::arweave_signer::ArweaveSigner};
+use crate::constants::SignatureConfig;
+use crate::signing::chains::{
+    arweave_signer::ArweaveSigner,
+    ed25519_signer::Ed25519Signer,
+    ethereum_signer::EthereumSigner,
+    injected_aptos_signer::InjectedAptosSigner,
+    multi_aptos_signer::MultiAptosSigner,
+    solana_signer::SolanaSigner,
+    typed_ethereum_signer::TypedEthereumSigner
+};
 use std::collections::HashMap;
 use once_cell::sync::OnceCell;
-use async_trait::async_trait;
 
-#[async_trait]
+/// Signature check that only needs the owner embedded in a data item, no private key
 pub trait IndexToTypeValueFn {
-    async fn verify(keypair_path: &str, pk: &Vec<u8>, message: &Vec<u8>, signature: &Vec<u8>) -> bool;
+    fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool;
 }
 
-pub enum IndexToTypeValue {
-    ArweaveSigner(ArweaveSigner)
-}
+pub type IndexToTypeValue = fn(&[u8], &[u8], &[u8]) -> bool;
 
-pub type IndexToType = HashMap<i64, IndexToTypeValue>;
+pub type IndexToType = HashMap<SignatureConfig, IndexToTypeValue>;
 
 static INDEX_TO_TYPE: OnceCell<IndexToType> = OnceCell::new();
-pub fn get_index_to_type<T: IndexToTypeValueFn>(jwk: JWKInterface, keypair_path: &str) -> &IndexToType {
+pub fn get_index_to_type() -> &'static IndexToType {
     INDEX_TO_TYPE.get_or_init(|| {
-        let index_to_type = HashMap::from([
-            (1, IndexToTypeValue::ArweaveSigner(ArweaveSigner::new(jwk, keypair_path)))
+        let index_to_type: IndexToType = HashMap::from([
+            (SignatureConfig::ARWEAVE, <ArweaveSigner as IndexToTypeValueFn>::verify as IndexToTypeValue),
+            (SignatureConfig::ED25519, <Ed25519Signer as IndexToTypeValueFn>::verify),
+            (SignatureConfig::ETHEREUM, <EthereumSigner as IndexToTypeValueFn>::verify),
+            (SignatureConfig::SOLANA, <SolanaSigner as IndexToTypeValueFn>::verify),
+            (SignatureConfig::INJECTEDAPTOS, <InjectedAptosSigner as IndexToTypeValueFn>::verify),
+            (SignatureConfig::MULTIAPTOS, <MultiAptosSigner as IndexToTypeValueFn>::verify),
+            (SignatureConfig::TYPEDETHEREUM, <TypedEthereumSigner as IndexToTypeValueFn>::verify)
         ]);
         index_to_type
     })
-}
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use crate::errors::ArBundleErrors;
+use crate::constants::{get_sig_config, SignatureConfig};
+use crate::signing::signer::Signer;
+
+/// Stands in for a signer whose private key lives elsewhere, e.g. on an air-gapped machine.
+/// It knows the owner, so unsigned items can be created with it, but it cannot sign: the
+/// signature comes back through `ar_data_bundle::apply_signing_request`.
+pub struct DetachedSigner {
+    signature_type: SignatureConfig,
+    owner_length: usize,
+    signature_length: usize,
+    public_key: Vec<u8>
+}
+
+impl DetachedSigner {
+    pub fn new(signature_type: SignatureConfig, public_key: Vec<u8>) -> Result<Self, ArBundleErrors> {
+        let sig_meta = match get_sig_config().get(&signature_type) {
+            Some(sig_meta) => sig_meta,
+            None => return Err(ArBundleErrors::SignatureConfigTypeNotFound)
+        };
+        if public_key.len() != sig_meta.pub_length {
+            return Err(ArBundleErrors::KeyCreationFailed(Some(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Owner must be {} bytes, but was incorrectly {}", sig_meta.pub_length, public_key.len())
+            )))));
+        }
+
+        Ok(Self {
+            signature_type,
+            owner_length: sig_meta.pub_length,
+            signature_length: sig_meta.sig_length,
+            public_key
+        })
+    }
+}
+
+impl Signer for DetachedSigner {
+    fn signature_type(&self) -> SignatureConfig {
+        self.signature_type
+    }
+
+    fn get_signature_length(&self) -> usize {
+        self.signature_length
+    }
+
+    fn get_owner_length(&self) -> usize {
+        self.owner_length
+    }
+
+    fn sign(&self, _message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        Err(ArBundleErrors::SignatureAttemptFailed)
+    }
+
+    fn get_public_key(&self) -> Vec<u8> {
+        self.public_key.clone()
+    }
+}
\ No newline at end of file
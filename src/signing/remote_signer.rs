@@ -0,0 +1,132 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use async_trait::async_trait;
+use once_cell::sync::OnceCell;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use crate::constants::{get_sig_config, SignatureConfig};
+use crate::errors::ArBundleErrors;
+use crate::signing::signer::AsyncSigner;
+
+/// Client for a signing service that keeps the private key to itself, spoken to over HTTP JSON-RPC 2.0.
+/// The service must answer two methods, with byte values base64url encoded:
+/// `getPublicKey` (no params) returning the owner, and `sign` (`{"message": ...}`) returning the signature.
+pub struct RemoteSigner {
+    url: String,
+    client: reqwest::Client,
+    signature_type: SignatureConfig,
+    owner_length: usize,
+    signature_length: usize,
+    public_key: OnceCell<Vec<u8>>,
+    next_request_id: AtomicU64
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse {
+    result: Option<Value>,
+    error: Option<JsonRpcError>
+}
+
+#[derive(Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String
+}
+
+impl RemoteSigner {
+    pub fn new(url: &str, signature_type: SignatureConfig) -> Result<Self, ArBundleErrors> {
+        Self::with_client(url, signature_type, reqwest::Client::new())
+    }
+
+    /// Uses a preconfigured client, e.g. one carrying auth headers or timeouts
+    pub fn with_client(url: &str, signature_type: SignatureConfig, client: reqwest::Client) -> Result<Self, ArBundleErrors> {
+        let sig_meta = match get_sig_config().get(&signature_type) {
+            Some(sig_meta) => sig_meta,
+            None => return Err(ArBundleErrors::SignatureConfigTypeNotFound)
+        };
+
+        Ok(Self {
+            url: url.to_string(),
+            client,
+            signature_type,
+            owner_length: sig_meta.pub_length,
+            signature_length: sig_meta.sig_length,
+            public_key: OnceCell::new(),
+            next_request_id: AtomicU64::new(1)
+        })
+    }
+
+    async fn call(&self, method: &str, params: Value) -> Result<Value, ArBundleErrors> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": self.next_request_id.fetch_add(1, Ordering::Relaxed),
+            "method": method,
+            "params": params
+        });
+
+        let response = self.client.post(&self.url)
+            .json(&request)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| ArBundleErrors::RemoteSignerFailed(e.to_string()))?;
+        let response: JsonRpcResponse = response.json()
+            .await
+            .map_err(|e| ArBundleErrors::RemoteSignerFailed(e.to_string()))?;
+
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(ArBundleErrors::RemoteSignerFailed(
+                format!("{} returned error {}: {}", method, error.code, error.message)
+            )),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(ArBundleErrors::RemoteSignerFailed(format!("{} returned no result", method)))
+        }
+    }
+
+    /// Calls `method` and decodes its base64url result, which must be `expected_length` bytes long
+    async fn call_for_bytes(&self, method: &str, params: Value, expected_length: usize) -> Result<Vec<u8>, ArBundleErrors> {
+        let result = self.call(method, params).await?;
+        let encoded = match result.as_str() {
+            Some(encoded) => encoded,
+            None => return Err(ArBundleErrors::RemoteSignerFailed(format!("{} result is not a string", method)))
+        };
+        let bytes = base64_url::decode(encoded)
+            .map_err(|e| ArBundleErrors::RemoteSignerFailed(format!("{} result is not base64url: {}", method, e)))?;
+        if bytes.len() != expected_length {
+            return Err(ArBundleErrors::RemoteSignerFailed(
+                format!("{} result must be {} bytes but was {}", method, expected_length, bytes.len())
+            ));
+        }
+
+        Ok(bytes)
+    }
+}
+
+#[async_trait]
+impl AsyncSigner for RemoteSigner {
+    fn signature_type(&self) -> SignatureConfig {
+        self.signature_type
+    }
+
+    fn get_signature_length(&self) -> usize {
+        self.signature_length
+    }
+
+    fn get_owner_length(&self) -> usize {
+        self.owner_length
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        let params = json!({ "message": base64_url::encode(message) });
+        self.call_for_bytes("sign", params, self.signature_length).await
+    }
+
+    /// Fetched once, then served from cache
+    async fn get_public_key(&self) -> Result<Vec<u8>, ArBundleErrors> {
+        if let Some(public_key) = self.public_key.get() {
+            return Ok(public_key.clone());
+        }
+
+        let public_key = self.call_for_bytes("getPublicKey", json!({}), self.owner_length).await?;
+        Ok(self.public_key.get_or_init(|| public_key).clone())
+    }
+}
\ No newline at end of file
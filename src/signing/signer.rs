@@ -1,36 +1,84 @@
-use std::any::Any;
-use crate:: errors::ArBundleErrors;
+use async_trait::async_trait;
+use crate::constants::SignatureConfig;
+use crate::errors::ArBundleErrors;
+use crate::signing::constants::get_index_to_type;
 
-pub enum StringOrVecu8 {
-    StringType(String),
-    BufferType(Vec<u8>)
-}
+/// Signs data items for one signature type. Object safe, so the signer can be picked at runtime
+/// and passed around as `Box<dyn Signer + Send + Sync>`.
+pub trait Signer {
+    fn signature_type(&self) -> SignatureConfig;
+    fn get_signature_length(&self) -> usize;
+    fn get_owner_length(&self) -> usize;
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors>;
+    fn get_public_key(&self) -> Vec<u8>;
 
-pub struct Signer {
-    pub signer: Option<Box<dyn Any>>, // any
-    pub public_key: Vec<u8>,
-    pub signature_type: i64,
-    pub signature_length: usize,
-    pub owner_length: usize,
-    pub pem: String,
-    pub keypair_path: String
+    /// Checks the signature with the verifier registered for `signature_type`
+    fn verify(&self, pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        match get_index_to_type().get(&self.signature_type()) {
+            Some(verify) => verify(pk, message, signature),
+            None => false
+        }
+    }
 }
 
-pub struct Options;
+impl<S: Signer + ?Sized> Signer for Box<S> {
+    fn signature_type(&self) -> SignatureConfig {
+        (**self).signature_type()
+    }
+
+    fn get_signature_length(&self) -> usize {
+        (**self).get_signature_length()
+    }
+
+    fn get_owner_length(&self) -> usize {
+        (**self).get_owner_length()
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        (**self).sign(message)
+    }
+
+    fn get_public_key(&self) -> Vec<u8> {
+        (**self).get_public_key()
+    }
 
-pub trait SignerMaker {
-    fn get_signature_type(&self) -> i64;
+    fn verify(&self, pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        (**self).verify(pk, message, signature)
+    }
+}
+
+/// Signer whose key lives behind an asynchronous boundary, e.g. a remote signing service.
+/// Every `Signer` is also an `AsyncSigner`, so the async apis accept local signers too.
+#[async_trait]
+pub trait AsyncSigner: Send + Sync {
+    fn signature_type(&self) -> SignatureConfig;
     fn get_signature_length(&self) -> usize;
     fn get_owner_length(&self) -> usize;
-    
-    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors>;
-    // async fn sign_data_item(data_item: StringOrVecu8, tags: Vec<Tag>) -> Result<DataItem, ArBundleErrors>;
-    // async fn set_public_key() -> Result<(), ArBundleErrors>;
-    // async fn get_address(&self) -> Result<String, ArBundleErrors>;
-    fn get_public_key(&self) -> Vec<u8>;
-    fn verify(&self, _pk: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        unimplemented!("You must implement verify method on child");
+
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors>;
+    async fn get_public_key(&self) -> Result<Vec<u8>, ArBundleErrors>;
+}
+
+#[async_trait]
+impl<T: Signer + Send + Sync + ?Sized> AsyncSigner for T {
+    fn signature_type(&self) -> SignatureConfig {
+        Signer::signature_type(self)
     }
 
-    fn get_keypair_path(&self) -> String;
+    fn get_signature_length(&self) -> usize {
+        Signer::get_signature_length(self)
+    }
+
+    fn get_owner_length(&self) -> usize {
+        Signer::get_owner_length(self)
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        Signer::sign(self, message)
+    }
+
+    async fn get_public_key(&self) -> Result<Vec<u8>, ArBundleErrors> {
+        Ok(Signer::get_public_key(self))
+    }
 }
\ No newline at end of file
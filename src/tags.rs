@@ -1,13 +1,104 @@
-use std::io::Write;
 use serde::{Deserialize, Serialize};
-use crate::{data_item::MAX_TAG_BYTES, errors::ArBundleErrors};
+use crate::{data_item::MAX_TAG_BYTES, errors::{ArBundleErrors, TagValidationError}};
 
-#[derive(Serialize, Deserialize)]
+pub const MAX_TAGS: usize = 128;
+pub const MAX_TAG_NAME_BYTES: usize = 1024;
+pub const MAX_TAG_VALUE_BYTES: usize = 3072;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Tag {
     pub name: Option<String>,
     pub value: Option<String>
 }
 
+/// Limits checked on tags when an item is created or parsed. The default is the ANS-104 limits, bundlers with
+/// stricter rules can lower them, limits above the ANS-104 ones are capped at those.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TagPolicy {
+    pub max_tags: usize,
+    pub max_name_bytes: usize,
+    pub max_value_bytes: usize,
+    /// Size of the whole Avro encoded tag array
+    pub max_tag_bytes: usize
+}
+
+impl Default for TagPolicy {
+    fn default() -> Self {
+        Self {
+            max_tags: MAX_TAGS,
+            max_name_bytes: MAX_TAG_NAME_BYTES,
+            max_value_bytes: MAX_TAG_VALUE_BYTES,
+            max_tag_bytes: MAX_TAG_BYTES
+        }
+    }
+}
+
+impl TagPolicy {
+    /// Every tag needs a non-empty name and value within the limits, the first one that doesn't is reported
+    pub fn validate(&self, tags: &[Tag]) -> Result<(), TagValidationError> {
+        let max_tags = self.max_tags.min(MAX_TAGS);
+        let max_name_bytes = self.max_name_bytes.min(MAX_TAG_NAME_BYTES);
+        let max_value_bytes = self.max_value_bytes.min(MAX_TAG_VALUE_BYTES);
+        let max_tag_bytes = self.max_tag_bytes.min(MAX_TAG_BYTES);
+
+        if tags.len() > max_tags {
+            return Err(TagValidationError::TooManyTags { count: tags.len(), max: max_tags });
+        }
+        for (index, tag) in tags.iter().enumerate() {
+            let name = match &tag.name {
+                Some(name) => name,
+                None => return Err(TagValidationError::MissingName { index })
+            };
+            let value = match &tag.value {
+                Some(value) => value,
+                None => return Err(TagValidationError::MissingValue { index })
+            };
+            if name.is_empty() {
+                return Err(TagValidationError::EmptyName { index });
+            }
+            if value.is_empty() {
+                return Err(TagValidationError::EmptyValue { index });
+            }
+            if name.len() > max_name_bytes {
+                return Err(TagValidationError::NameTooLong { index, length: name.len(), max: max_name_bytes });
+            }
+            if value.len() > max_value_bytes {
+                return Err(TagValidationError::ValueTooLong { index, length: value.len(), max: max_value_bytes });
+            }
+        }
+
+        let length = encoded_length(tags);
+        if length > max_tag_bytes {
+            return Err(TagValidationError::TooManyBytes { length, max: max_tag_bytes });
+        }
+        Ok(())
+    }
+}
+
+/// Bytes `serialize_tags` writes for `tags`, which must all have a name and a value
+fn encoded_length(tags: &[Tag]) -> usize {
+    if tags.is_empty() {
+        return 0;
+    }
+    let string_length = |s: &Option<String>| s.as_ref().map_or(0, |s| long_length(s.len() as i64) + s.len());
+    let items: usize = tags.iter().map(|tag| string_length(&tag.name) + string_length(&tag.value)).sum();
+    long_length(tags.len() as i64) + items + long_length(0)
+}
+
+/// Bytes taken by the zigzag varint of `n`
+fn long_length(n: i64) -> usize {
+    let mut m = ((n << 1) ^ (n >> 63)) as u64;
+    let mut length = 1;
+    while m & !0x7f != 0 {
+        m >>= 7;
+        length += 1;
+    }
+    length
+}
+
+/// Avro codec for the tags of a data item, which are an `array` of `{ name: bytes, value: bytes }` records.
+/// Writes the way avsc does (one block with a positive count, then the 0 terminator) so ids match the JS
+/// library, and reads any valid encoding, including blocks with a negative count followed by their size.
 #[derive(Debug)]
 pub struct AVSCTap {
     buf: Vec<u8>,
@@ -15,265 +106,164 @@ pub struct AVSCTap {
 }
 
 impl AVSCTap {
+    /// `None` starts an empty buffer to write into, `Some` reads from `buf` starting at `pos`
     pub fn new(buf: Option<Vec<u8>>, pos: Option<usize>) -> Self {
         Self {
-            buf: if buf.is_none() { 
-                let mut vec = Vec::with_capacity(MAX_TAG_BYTES);
-                vec.fill(0);
-                println!("vec {:?}", vec.clone());
-                vec
-            } else { 
-                buf.unwrap() 
-            },
-            pos: if pos.is_none() { 0 } else { pos.unwrap() }
+            buf: buf.unwrap_or_default(),
+            pos: pos.unwrap_or(0)
         }
     }
 
-    pub fn write_tags(&mut self, tags: &Vec<Tag>) -> Result<(), ArBundleErrors> {
-        let n = tags.len();
-        println!("write_tags n {}", n);
-
-        if n > 0 {
-            _ = self.write_long(n as i64);
-            for i in 0..n {
-                let tag = tags.get(i);
-                if tag.is_none() || tag.unwrap().name.is_none() || tag.unwrap().value.is_none() {
-                    return Err(ArBundleErrors::TagIsUndefinedOrEmpty)
+    pub fn write_tags(&mut self, tags: &[Tag]) -> Result<(), ArBundleErrors> {
+        if !tags.is_empty() {
+            self.write_long(tags.len() as i64);
+            for tag in tags {
+                match (&tag.name, &tag.value) {
+                    (Some(name), Some(value)) => {
+                        self.write_string(name);
+                        self.write_string(value);
+                    },
+                    _ => return Err(ArBundleErrors::TagIsUndefinedOrEmpty)
                 }
-                _ = self.write_string(if tag.unwrap().name.is_none() { "" } else { &tag.unwrap().name.as_ref().unwrap() });
-                _ = self.write_string(if tag.unwrap().value.is_none() { "" } else { &tag.unwrap().value.as_ref().unwrap() });
             }
         }
-        _ = self.write_long(0);
+        self.write_long(0);
 
         Ok(())
     }
 
-    pub fn to_buffer(&mut self) -> Result<Vec<u8>, ArBundleErrors> {        
-        if self.pos > self.buf.len() { 
-            return Err(ArBundleErrors::IoFailure(
-                std::io::Error::new(std::io::ErrorKind::Other, format!("Too many tag bytes ({:?} > {})", self.pos, self.buf.len()))
-            ));
-        }
-        
-        let mut buffer: Vec<u8> = Vec::with_capacity(self.pos);
-        match buffer.write_all(&self.buf[..self.pos]) {
-            Ok(_) => {},
-            Err(e) => return Err(ArBundleErrors::IoFailure(e))
+    pub fn to_buffer(&self) -> Result<Vec<u8>, ArBundleErrors> {
+        if self.pos > MAX_TAG_BYTES {
+            return Err(ArBundleErrors::InvalidTag(TagValidationError::TooManyBytes { length: self.pos, max: MAX_TAG_BYTES }));
         }
-        return Ok(buffer);
+        Ok(self.buf[..self.pos].to_vec())
     }
 
-    pub fn write_long(&mut self, n: i64) -> Result<(), ArBundleErrors> {
-        let buf = &mut self.buf;
-        println!("write_long buf: {:?}", buf);
-        let mut f: f64;
-        let mut m: i64;
-    
-        if n >= -1073741824 && n < 1073741824 {
-            // Won't overflow, use integer arithmetic
-            m = if n >= 0 { n << 1 } else { (!n << 1) | 1 };
-            loop {
-                buf[self.pos] = (m & 0x7f) as u8;
-                m >>= 7;
-
-                self.pos += 1;
-                if m == 0 && (buf[self.pos] & 0x80 == 0) {
-                    break;
-                }                
-            }
-        } else {
-            // Use slower floating-point arithmetic
-            f = if n >= 0 { n as f64 * 2.0 } else { -n as f64 * 2.0 - 1.0 };
-            loop {
-                buf[self.pos] = (f as i32 & 0x7f) as u8;
-                f /= 128.0;
-                if f < 1.0 && (buf[self.pos] & 0x80 == 0) {
-                    break;
-                }
-                self.pos += 1;
-            }
+    /// Zigzag encoded varint
+    pub fn write_long(&mut self, n: i64) {
+        let mut m = ((n << 1) ^ (n >> 63)) as u64;
+        while m & !0x7f != 0 {
+            self.write_byte((m & 0x7f) as u8 | 0x80);
+            m >>= 7;
         }
-    
-        self.pos += 1; // Update position (assuming it's a u8)
-        self.buf = buf.clone();
-        Ok(())
+        self.write_byte(m as u8);
     }
 
-    pub fn write_string(&mut self, s: &str) -> Result<(), ArBundleErrors> {
-        let len = s.len();        
-        self.write_long(len as i64)?; 
-        let buf = &mut self.buf;
-
-        let mut pos = self.pos;
-        self.pos += len;
-        if self.pos > buf.len() {
-            return Err(ArBundleErrors::IoFailure(std::io::Error::new(std::io::ErrorKind::Other, "Buffer overflow")));
+    /// Length prefixed, Avro `string` and `bytes` share this encoding
+    pub fn write_string(&mut self, s: &str) {
+        self.write_long(s.len() as i64);
+        for byte in s.as_bytes() {
+            self.write_byte(*byte);
         }
+    }
 
-        if len > 64 {
-            buf[pos - len..pos].copy_from_slice(s.as_bytes());
+    fn write_byte(&mut self, byte: u8) {
+        if self.pos < self.buf.len() {
+            self.buf[self.pos] = byte;
         } else {
-            #[allow(unused)]
-            let mut c2: u32 = 0;
-            let mut i = 0;
-            for c in s.chars() {                
-                let mut c1 = c as u32;                
-
-                if c1 < 0x80 {
-                    pos += 1;
-                    buf[pos] = c1 as u8;                    
-                } else if c1 < 0x800 {
-                    pos += 1;
-                    buf[pos] = (c1 >> 6) as u8 | 0xc0;
-                    pos += 1;
-                    buf[pos] = (c1 & 0x3f) as u8 | 0x80;
-                } else if c1 & 0xfc00 == 0xd800 {
-                    c2 = if s.chars().nth(i + 1).is_some() { 
-                        if s.chars().nth(i + 1).is_some() {
-                            s.chars().nth(i + 1).unwrap() as u32 
-                        } else {
-                            0
-                        }
-                    } else { 0 };
-                    c2 = c2 & 0xfc00;
-                    if c2 == 0xdc00 {
-                        c1 = 0x10000 + ((c1 & 0x03ff) << 10) + (c2 & 0x03ff);
-                        i += 1;
-                        pos += 1;
-                        buf[pos] = (c1 >> 18) as u8 | 0xf0;
-                        pos += 1;
-                        buf[pos] = ((c1 >> 12) & 0x3f) as u8 | 0x80;
-                        pos += 1;
-                        buf[pos] = ((c1 >> 6) & 0x3f) as u8 | 0x80;
-                        pos += 1;
-                        buf[pos] = (c1 & 0x3f) as u8 | 0x80;
-                    }                    
-                } else {
-                    pos += 1;
-                    buf[pos] = (c1 >> 12) as u8 | 0xe0;
-                    pos += 1;
-                    buf[pos] = ((c1 >> 6) & 0x3f) as u8 | 0x80;
-                    pos += 1;
-                    buf[pos] = (c1 & 0x3f) as u8 | 0x80;                                        
-                }   
-                i += 1;             
-            }
+            self.buf.push(byte);
         }
-
-        self.buf = buf.clone();
-        Ok(())
+        self.pos += 1;
     }
 
-    fn read_long(&mut self) -> Option<i64> {
-        let mut n = 0;
+    /// Zigzag varint, at most 64 bits of payload
+    fn read_long(&mut self) -> Result<i64, ArBundleErrors> {
+        let mut n: u64 = 0;
         let mut k = 0;
-        let buf = &self.buf;
-        let mut b: u8;
-        let mut h: u8;
-        let mut f: i32;
-        let mut fk: i32;
-    
+
         loop {
-            if self.pos >= buf.len() {
-                return None;
+            if k >= 64 {
+                return Err(ArBundleErrors::InvalidTagEncoding { offset: self.pos });
             }
-
-            self.pos += 1;
-            b = buf[self.pos];
-            h = b & 0x80;
-            n |= (b as i32 & 0x7f) << k;
+            let b = self.read_byte()?;
+            n |= ((b & 0x7f) as u64) << k;
             k += 7;
-            if h == 0 || k >= 28 {
-                break;
-            }
-        }
-    
-        if h != 0 {
-          // Switch to float arithmetic, otherwise we might overflow.
-          f = n;
-          fk = 268435456; // 2 ** 28.
-          loop {
-            if self.pos >= buf.len() {
-                return None;
-            }
-            self.pos += 1;
-            b = buf[self.pos];
-            f += (b as i32 & 0x7f) * fk;
-            fk *= 128;
-
             if b & 0x80 == 0 {
                 break;
             }
-          }
-          return Some(((if f % 2 != 0 { -(f + 1) } else { f }) / 2) as i64);
         }
-    
-        Some(((n >> 1) ^ -(n & 1)) as i64)
+
+        Ok((n >> 1) as i64 ^ -((n & 1) as i64))
     }
 
-    pub fn skip_long(&mut self) {
-        let buf = &self.buf;
+    pub fn skip_long(&mut self) -> Result<(), ArBundleErrors> {
+        while self.read_byte()? & 0x80 != 0 {}
+        Ok(())
+    }
 
-        loop {
-            self.pos += 1;
-            
-            if buf[self.pos] & 0x80 == 0 {
-                break;
-            }
+    fn read_byte(&mut self) -> Result<u8, ArBundleErrors> {
+        match self.buf.get(self.pos) {
+            Some(b) => {
+                self.pos += 1;
+                Ok(*b)
+            },
+            None => Err(ArBundleErrors::Truncated { field: "tags", offset: self.pos })
         }
     }
-  
-    pub fn read_tags(&mut self) -> Vec<Tag> {
-        // var items = this.itemsType;
+
+    /// Reads blocks until the 0 count that ends the array
+    pub fn read_tags(&mut self) -> Result<Vec<Tag>, ArBundleErrors> {
         let mut val: Vec<Tag> = vec![];
-        #[allow(unused)]
-        let mut n = 0;
         loop {
-            n = if self.read_long().is_none() { 0 } else {self.read_long().unwrap() };
-            if n < 0 {
-                n = -n;
-                self.skip_long(); // Skip size.
-            }
-            for _ in (0..n).rev() {
-                let name = self.read_string();
-                let value = self.read_string();
-                val.push(Tag { 
-                    name: if name.is_ok() { name.unwrap() } else { Some("".to_string()) }, 
-                    value: if value.is_ok() { value.unwrap() } else { Some("".to_string()) }
-                });
-            }
+            let block_start = self.pos;
+            let mut n = self.read_long()?;
             if n == 0 {
                 break;
             }
+            if n < 0 {
+                n = match n.checked_neg() {
+                    Some(n) => n,
+                    None => return Err(ArBundleErrors::InvalidTagEncoding { offset: block_start })
+                };
+                self.skip_long()?; // Skip size.
+            }
+            for _ in 0..n {
+                let index = val.len();
+                let (name, value) = match (String::from_utf8(self.read_bytes()?), String::from_utf8(self.read_bytes()?)) {
+                    (Ok(name), Ok(value)) => (name, value),
+                    _ => return Err(ArBundleErrors::InvalidTag(TagValidationError::InvalidUtf8 { index }))
+                };
+                val.push(Tag { name: Some(name), value: Some(value) });
+            }
         }
-        return val;
+        Ok(val)
     }
 
-    pub fn read_string(&mut self) -> Result<Option<String>, ArBundleErrors> {
-        let len = if self.read_long().is_none() { 0 } else { self.read_long().unwrap() };
+    pub fn read_string(&mut self) -> Result<String, ArBundleErrors> {
         let pos = self.pos;
-        self.pos += len as usize;
-        if self.pos > self.buf.len() {
-          return Ok(None);
+        match String::from_utf8(self.read_bytes()?) {
+            Ok(str) => Ok(str),
+            Err(_) => Err(ArBundleErrors::InvalidTagEncoding { offset: pos })
         }
-        match String::from_utf8(self.buf[pos..pos + len as usize].to_vec()) {
-            Ok(str) => Ok(Some(str)),
-            Err(_) => Err(ArBundleErrors::IoFailure(
-                std::io::Error::new(std::io::ErrorKind::Other,
-                "Vec<u8> to string error".to_string())
-            ))
+    }
+
+    fn read_bytes(&mut self) -> Result<Vec<u8>, ArBundleErrors> {
+        let len_start = self.pos;
+        let len = self.read_long()?;
+        if len < 0 {
+            return Err(ArBundleErrors::InvalidTagEncoding { offset: len_start });
         }
+        let pos = self.pos;
+        if len as usize > self.buf.len() - pos {
+            return Err(ArBundleErrors::Truncated { field: "tag", offset: pos });
+        }
+        self.pos += len as usize;
+        Ok(self.buf[pos..self.pos].to_vec())
     }
 }
 
-pub fn serialize_tags(tags: &Vec<Tag>) -> Result<Vec<u8>, ArBundleErrors> {
+pub fn serialize_tags(tags: &[Tag]) -> Result<Vec<u8>, ArBundleErrors> {
     let mut tap = AVSCTap::new(None, None);
-    _ = tap.write_tags(tags);
-    return tap.to_buffer();
+    tap.write_tags(tags)?;
+    tap.to_buffer()
 }
 
-pub fn deserialize_tags(tags_buffer: Vec<u8>) -> Vec<Tag> {
+/// Decodes tags written by `serialize_tags` or any other Avro encoder, bytes after the array are rejected
+pub fn deserialize_tags(tags_buffer: Vec<u8>) -> Result<Vec<Tag>, ArBundleErrors> {
     let mut tap = AVSCTap::new(Some(tags_buffer), None);
-    return tap.read_tags();
+    let tags = tap.read_tags()?;
+    if tap.pos != tap.buf.len() {
+        return Err(ArBundleErrors::TrailingBytes { offset: tap.pos });
+    }
+    Ok(tags)
 }
\ No newline at end of file
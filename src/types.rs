@@ -0,0 +1,90 @@
+use std::{fmt::Display, str::FromStr};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use crate::errors::ArBundleErrors;
+
+/// Declares a 32 byte value that travels as 43 characters of base64url, the way Arweave writes ids
+macro_rules! base64_url_32_bytes {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $name([u8; 32]);
+
+        impl $name {
+            pub fn new(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+
+            pub fn as_bytes(&self) -> &[u8; 32] {
+                &self.0
+            }
+        }
+
+        impl From<[u8; 32]> for $name {
+            fn from(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = ArBundleErrors;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                match <[u8; 32]>::try_from(bytes) {
+                    Ok(bytes) => Ok(Self(bytes)),
+                    Err(_) => Err(ArBundleErrors::IoFailure(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("{} must be 32 bytes but was incorrectly {}", stringify!($name), bytes.len())
+                    )))
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ArBundleErrors;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match base64_url::decode(s) {
+                    Ok(bytes) => Self::try_from(bytes.as_slice()),
+                    Err(e) => Err(ArBundleErrors::IoFailure(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("{} is not base64url: {}", stringify!($name), e)
+                    )))
+                }
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", base64_url::encode(&self.0))
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let encoded = String::deserialize(deserializer)?;
+                encoded.parse().map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+base64_url_32_bytes!(
+    /// Id of a transaction or data item, the sha256 of its signature
+    TxId
+);
+
+base64_url_32_bytes!(
+    /// Wallet address, the sha256 of the owner's public key
+    ArweaveAddress
+);
+
+base64_url_32_bytes!(
+    /// Value of a data item's anchor field, e.g. 32 random bytes to keep ids of identical items apart
+    Anchor
+);
\ No newline at end of file
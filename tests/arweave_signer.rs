@@ -0,0 +1,80 @@
+use ar_bundles::{
+    ar_data_bundle::sign,
+    ar_data_create::{create_data, Data},
+    data_item::SignedDataItem,
+    errors::ArBundleErrors,
+    interface_jwk::JWKInterface,
+    key_utils::{generate_arweave_jwk, owner_to_address},
+    signing::{chains::arweave_signer::ArweaveSigner, signer::Signer}
+};
+use common::ARWEAVE_WALLET;
+
+mod common;
+
+const WALLET_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/arweave_wallet.json");
+
+fn wallet() -> JWKInterface {
+    serde_json::from_str(ARWEAVE_WALLET).unwrap()
+}
+
+#[test]
+fn new_accepts_the_matching_jwk() {
+    let signer = ArweaveSigner::new(wallet(), WALLET_PATH).unwrap();
+    assert_eq!(signer.public_key(), wallet().n);
+    assert_eq!(signer.get_keypair_path(), WALLET_PATH);
+
+    // only the public half is compared, so a public JWK is enough
+    let mut public_only = wallet();
+    public_only.d = None;
+    assert!(ArweaveSigner::new(public_only, WALLET_PATH).is_ok());
+}
+
+#[test]
+fn new_rejects_a_jwk_for_another_wallet() {
+    let mut other = wallet();
+    let mut modulus = base64_url::decode(&other.n).unwrap();
+    modulus[100] ^= 1;
+    other.n = base64_url::encode(&modulus);
+    assert!(matches!(ArweaveSigner::new(other, WALLET_PATH), Err(ArBundleErrors::KeyCreationFailed(_))));
+
+    let mut garbage = wallet();
+    garbage.n = "not base64!".to_string();
+    assert!(matches!(ArweaveSigner::new(garbage, WALLET_PATH), Err(ArBundleErrors::KeyCreationFailed(_))));
+}
+
+#[test]
+fn signers_report_the_loaded_key() {
+    let signer = ArweaveSigner::from_keypair_path(WALLET_PATH).unwrap();
+    assert_eq!(base64_url::encode(&signer.get_public_key()), wallet().n);
+    let clone = signer.clone();
+    assert_eq!(clone.public_key(), signer.public_key());
+}
+
+#[test]
+fn known_wallet_has_the_known_address() {
+    // base64url(sha256(n)) of the fixture wallet, computed with python's hashlib
+    let signer = ArweaveSigner::from_jwk_str(ARWEAVE_WALLET).unwrap();
+    assert_eq!(signer.address().to_string(), "IcKOjX0GRTSI4EX4lFxV4UnxNVhypnW_kBFwerLI3Gg");
+    assert_eq!(owner_to_address(&signer.get_public_key()), signer.address());
+}
+
+#[test]
+fn generated_wallets_round_trip_through_json() {
+    let jwk = generate_arweave_jwk().unwrap();
+    assert_eq!(jwk.kty, "RSA");
+    assert_eq!(jwk.e, "AQAB");
+    assert_eq!(base64_url::decode(&jwk.n).unwrap().len(), 512);
+    for component in [&jwk.d, &jwk.p, &jwk.q, &jwk.dp, &jwk.dq, &jwk.qi] {
+        assert!(component.is_some());
+    }
+
+    let wallet_json = serde_json::to_string(&jwk).unwrap();
+    let signer = ArweaveSigner::from_jwk_str(&wallet_json).unwrap();
+    assert_eq!(signer.public_key(), jwk.n);
+    assert_eq!(signer.address(), owner_to_address(&signer.get_public_key()));
+
+    let unsigned = create_data(Data::StringData("fresh wallet".to_string()), &signer, None).unwrap();
+    let item = sign(unsigned, &signer).unwrap();
+    assert!(SignedDataItem::verify_bytes(&item.get_raw()).unwrap().is_valid());
+    assert_eq!(item.owner_address().unwrap(), signer.address().to_string());
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use ar_bundles::{
+    ar_data_bundle::bundle_and_sign,
+    ar_data_create::{create_data, Data, DataItemCreateOptions},
+    bundle::Bundle,
+    data_item::UnsignedDataItem,
+    errors::ArBundleErrors,
+    types::TxId
+};
+use common::{ed25519_signer, tag};
+
+mod common;
+
+fn unsigned_items() -> Vec<UnsignedDataItem> {
+    let signer = ed25519_signer();
+    let opts = DataItemCreateOptions { target: None, anchor: None, tags: Some(vec![tag("Content-Type", "text/plain")]) };
+    vec![
+        create_data(Data::StringData("first".to_string()), &signer, Some(&opts)).unwrap(),
+        create_data(Data::BinaryData(vec![2; 300]), &signer, None).unwrap(),
+        create_data(Data::BinaryData(vec![]), &signer, None).unwrap()
+    ]
+}
+
+#[test]
+fn signed_bundle_round_trips() {
+    let signed = bundle_and_sign(unsigned_items(), &ed25519_signer()).unwrap();
+    let bundle = Bundle::from_bytes(signed.get_raw()).unwrap();
+    let items = bundle.items().unwrap();
+
+    assert_eq!(bundle.length(), 3);
+    assert_eq!(bundle.get_sizes(), items.iter().map(|item| item.get_raw_size()).collect::<Vec<usize>>());
+    assert_eq!(bundle.get_ids(), items.iter().map(|item| item.id()).collect::<Vec<TxId>>());
+
+    for (index, id) in bundle.get_ids().iter().enumerate() {
+        let by_id = bundle.get_by_id(id).unwrap();
+        let by_index = bundle.get_by_index(index).unwrap();
+        assert_eq!(by_id.id(), *id);
+        assert_eq!(by_index.get_raw(), by_id.get_raw());
+        assert_eq!(bundle.get_id_by(index).unwrap(), *id);
+        assert_eq!(bundle.get_ref_by_index(index).unwrap().id(), *id);
+    }
+    assert_eq!(bundle.get_by_index(0).unwrap().raw_data(), b"first");
+    assert_eq!(bundle.get_by_index(1).unwrap().raw_data(), &[2; 300]);
+    assert!(bundle.get_by_index(2).unwrap().raw_data().is_empty());
+
+    assert!(matches!(bundle.get_by_index(3), Err(ArBundleErrors::DataItemNotFound)));
+    assert!(matches!(bundle.get_by_id(&TxId::new([0; 32])), Err(ArBundleErrors::DataItemNotFound)));
+}
+
+#[test]
+fn header_ids_must_match_their_items() {
+    let mut binary = bundle_and_sign(unsigned_items(), &ed25519_signer()).unwrap().get_raw();
+    // swap the ids of the first two header entries, so each claims the other's item
+    let first_id = binary[64..96].to_vec();
+    let second_id = binary[128..160].to_vec();
+    binary[64..96].copy_from_slice(&second_id);
+    binary[128..160].copy_from_slice(&first_id);
+
+    let bundle = Bundle::from_bytes(binary).unwrap();
+    let first_id = TxId::try_from(first_id.as_slice()).unwrap();
+    assert!(matches!(bundle.get_by_id(&first_id), Err(ArBundleErrors::BundleIdMismatch { index: 1 })));
+    assert!(matches!(bundle.get_by_index(0), Err(ArBundleErrors::BundleIdMismatch { index: 0 })));
+    assert!(matches!(bundle.items(), Err(ArBundleErrors::BundleIdMismatch { index: 0 })));
+    assert!(matches!(bundle.item_refs(), Err(ArBundleErrors::BundleIdMismatch { index: 0 })));
+    assert!(bundle.get_by_index(2).is_ok());
+}
\ No newline at end of file
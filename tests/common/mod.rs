@@ -0,0 +1,39 @@
+//! Fixtures shared by the integration tests, each test binary only uses some of them
+#![allow(dead_code)]
+
+use ar_bundles::{
+    signing::chains::{arweave_signer::ArweaveSigner, ed25519_signer::Ed25519Signer},
+    tags::Tag,
+    utils::long_to_8_byte_array
+};
+
+/// Fixed 4096 bit Arweave wallet, generating one per test is too slow
+pub const ARWEAVE_WALLET: &str = include_str!("../fixtures/arweave_wallet.json");
+
+/// Deterministic ed25519 key for tests that only need some valid signer
+pub fn ed25519_signer() -> Ed25519Signer {
+    Ed25519Signer::new(&[9u8; 32]).unwrap()
+}
+
+pub fn arweave_signer() -> ArweaveSigner {
+    ArweaveSigner::from_jwk_str(ARWEAVE_WALLET).unwrap()
+}
+
+pub fn tag(name: &str, value: &str) -> Tag {
+    Tag { name: Some(name.to_string()), value: Some(value.to_string()) }
+}
+
+/// Ed25519 item with a dummy non-zero signature: 2 + 64 + 32 + target + anchor + 16 + tags + data.
+/// Every field is written as given, so the layout and the tag limits can be broken at will.
+pub fn raw_item(target: &[u8], anchor: &[u8], tag_count: i64, tags: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![2, 0];
+    bytes.extend_from_slice(&[1u8; 64]);
+    bytes.extend_from_slice(&[7u8; 32]);
+    bytes.extend_from_slice(target);
+    bytes.extend_from_slice(anchor);
+    bytes.extend_from_slice(&long_to_8_byte_array(tag_count));
+    bytes.extend_from_slice(&long_to_8_byte_array(tags.len() as i64));
+    bytes.extend_from_slice(tags);
+    bytes.extend_from_slice(data);
+    bytes
+}
\ No newline at end of file
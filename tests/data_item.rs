@@ -0,0 +1,95 @@
+use ar_bundles::{
+    ar_data_bundle::sign,
+    ar_data_create::{create_data, Data, DataItemCreateOptions},
+    constants::SignatureConfig,
+    data_item::SignedDataItem,
+    signing::signer::Signer,
+    types::{Anchor, TxId}
+};
+use common::{ed25519_signer, tag};
+
+mod common;
+
+#[test]
+fn parsed_fields_match_what_was_built() {
+    let signer = ed25519_signer();
+    let target = TxId::new([3; 32]);
+    let anchor = Anchor::new([4; 32]);
+    let tags = vec![tag("Content-Type", "text/plain"), tag("App-Name", "ar-bundles")];
+    let opts = DataItemCreateOptions { target: Some(target), anchor: Some(anchor), tags: Some(tags.clone()) };
+    let unsigned = create_data(Data::StringData("hello".to_string()), &signer, Some(&opts)).unwrap();
+
+    assert_eq!(unsigned.raw_owner(), signer.get_public_key().as_slice());
+    assert_eq!(unsigned.target(), Some(target));
+    assert_eq!(unsigned.anchor(), Some(anchor));
+    assert!(unsigned.raw_signature().iter().all(|byte| *byte == 0));
+
+    let item = SignedDataItem::from_bytes(sign(unsigned, &signer).unwrap().get_raw()).unwrap();
+    assert_eq!(item.get_signature_type().unwrap(), SignatureConfig::ED25519);
+    assert_eq!(item.raw_signature().len(), 64);
+    assert_eq!(item.signature(), base64_url::encode(item.raw_signature()));
+    assert_eq!(item.raw_owner(), signer.get_public_key().as_slice());
+    assert_eq!(item.owner(), base64_url::encode(&signer.get_public_key()));
+    assert_eq!(item.target(), Some(target));
+    assert_eq!(item.raw_target(), &[3; 32]);
+    assert_eq!(item.anchor(), Some(anchor));
+    assert_eq!(item.raw_anchor(), &[4; 32]);
+    assert_eq!(item.tags(), tags.as_slice());
+    assert_eq!(item.view().tag_count(), 2);
+    assert_eq!(item.raw_data(), b"hello");
+    assert_eq!(item.data(), base64_url::encode(b"hello"));
+    // 2 + 64 + 32 + 33 + 33 + 16 + tags + data
+    assert_eq!(item.get_raw_size(), 180 + item.raw_tags().len() + 5);
+}
+
+#[test]
+fn parsed_fields_without_target_or_anchor() {
+    let signer = ed25519_signer();
+    let unsigned = create_data(Data::BinaryData(vec![0, 1, 2]), &signer, None).unwrap();
+    let item = SignedDataItem::from_bytes(sign(unsigned, &signer).unwrap().get_raw()).unwrap();
+
+    assert_eq!(item.raw_owner(), signer.get_public_key().as_slice());
+    assert_eq!(item.target(), None);
+    assert!(item.raw_target().is_empty());
+    assert_eq!(item.anchor(), None);
+    assert!(item.raw_anchor().is_empty());
+    assert!(item.tags().is_empty());
+    assert!(item.raw_tags().is_empty());
+    assert_eq!(item.raw_data(), &[0, 1, 2]);
+    // 2 + 64 + 32 + 1 + 1 + 16 + data
+    assert_eq!(item.get_raw_size(), 116 + 3);
+
+    let binary = item.get_raw();
+    assert_eq!(binary[98], 0);
+    assert_eq!(binary[99], 0);
+    assert!(SignedDataItem::verify_bytes(&binary).unwrap().is_valid());
+}
+
+#[test]
+fn target_without_anchor_and_anchor_without_target() {
+    let signer = ed25519_signer();
+    for (target, anchor) in [(Some(TxId::new([5; 32])), None), (None, Some(Anchor::new([6; 32])))] {
+        let opts = DataItemCreateOptions { target, anchor, tags: None };
+        let unsigned = create_data(Data::BinaryData(b"data".to_vec()), &signer, Some(&opts)).unwrap();
+        let item = SignedDataItem::from_bytes(sign(unsigned, &signer).unwrap().get_raw()).unwrap();
+        assert_eq!(item.target(), target);
+        assert_eq!(item.anchor(), anchor);
+        assert_eq!(item.raw_data(), b"data");
+    }
+}
+
+#[test]
+fn item_refs_are_shared_across_threads() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let signer = ed25519_signer();
+    let item = sign(create_data(Data::StringData("shared".to_string()), &signer, None).unwrap(), &signer).unwrap();
+    let view = item.view();
+    assert_send_sync(&view);
+
+    let ids: Vec<TxId> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4).map(|_| scope.spawn(|| view.id())).collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+    assert!(ids.iter().all(|id| *id == item.id()));
+}
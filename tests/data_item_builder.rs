@@ -0,0 +1,83 @@
+use std::io::Cursor;
+use ar_bundles::{
+    ar_data_bundle::{get_signing_request, sign},
+    ar_data_create::{create_data, Data, DataItemBuilder, DataItemCreateOptions},
+    data_item::UnsignedDataItem,
+    errors::ArBundleErrors,
+    types::{Anchor, TxId}
+};
+use common::{ed25519_signer, tag};
+
+mod common;
+
+/// The item's bytes as exported for detached signing
+fn serialized(item: &UnsignedDataItem) -> String {
+    get_signing_request(item).unwrap().unsigned_item
+}
+
+#[test]
+fn build_unsigned_matches_create_data() {
+    let signer = ed25519_signer();
+    let built = DataItemBuilder::new()
+        .data_from_bytes(b"same inputs".to_vec())
+        .target(TxId::new([1; 32]))
+        .anchor(Anchor::new([2; 32]))
+        .tag("Content-Type", "text/plain")
+        .tag("App-Name", "ar-bundles")
+        .build_unsigned(&signer)
+        .unwrap();
+    let opts = DataItemCreateOptions {
+        target: Some(TxId::new([1; 32])),
+        anchor: Some(Anchor::new([2; 32])),
+        tags: Some(vec![tag("Content-Type", "text/plain"), tag("App-Name", "ar-bundles")])
+    };
+    let created = create_data(Data::BinaryData(b"same inputs".to_vec()), &signer, Some(&opts)).unwrap();
+    assert_eq!(serialized(&built), serialized(&created));
+
+    let built = DataItemBuilder::new().build_unsigned(&signer).unwrap();
+    let created = create_data(Data::BinaryData(vec![]), &signer, None).unwrap();
+    assert_eq!(serialized(&built), serialized(&created));
+
+    // ed25519 signatures are deterministic, so the signed items match too
+    let signed = DataItemBuilder::new().data_from_bytes(b"x".to_vec()).sign(&signer).unwrap();
+    let created = sign(create_data(Data::StringData("x".to_string()), &signer, None).unwrap(), &signer).unwrap();
+    assert_eq!(signed.id(), created.id());
+}
+
+#[test]
+fn data_from_reader_reads_everything() {
+    let data: Vec<u8> = (0..100_000u32).map(|i| i as u8).collect();
+    let item = DataItemBuilder::new()
+        .data_from_reader(&mut Cursor::new(data.clone()))
+        .unwrap()
+        .sign(&ed25519_signer())
+        .unwrap();
+    assert_eq!(item.raw_data(), data.as_slice());
+}
+
+#[test]
+fn data_from_file_reads_the_file() {
+    let path = std::env::temp_dir().join(format!("ar-bundles-builder-{}.bin", std::process::id()));
+    std::fs::write(&path, b"from a file").unwrap();
+    let built = DataItemBuilder::new().data_from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+    let item = built.unwrap().sign(&ed25519_signer()).unwrap();
+    assert_eq!(item.raw_data(), b"from a file");
+
+    let missing = DataItemBuilder::new().data_from_file("/nonexistent/data.bin");
+    assert!(matches!(missing, Err(ArBundleErrors::IoFailure(_))));
+}
+
+#[test]
+fn random_anchors_give_distinct_ids() {
+    let signer = ed25519_signer();
+    let build = || DataItemBuilder::new().data_from_bytes(b"same data".to_vec()).random_anchor().sign(&signer).unwrap();
+    let first = build();
+    let second = build();
+    assert!(first.anchor().is_some());
+    assert_ne!(first.anchor(), second.anchor());
+    assert_ne!(first.id(), second.id());
+
+    let without_anchor = || DataItemBuilder::new().data_from_bytes(b"same data".to_vec()).sign(&signer).unwrap();
+    assert_eq!(without_anchor().id(), without_anchor().id());
+}
\ No newline at end of file
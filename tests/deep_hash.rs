@@ -0,0 +1,97 @@
+use ar_bundles::{
+    ar_data_base::get_signature_data,
+    ar_data_bundle::sign,
+    ar_data_create::{create_data, Data, DataItemCreateOptions},
+    data_item::SignedDataItem,
+    deep_hash::{deep_hash, DeepHashChunk},
+    signing::chains::ed25519_signer::Ed25519Signer,
+    types::{Anchor, TxId}
+};
+use common::tag;
+
+mod common;
+
+// ed25519 item in the arbundles layout: seed 0x00..0x1f, target 32 x 0xaa, a 32 byte ascii anchor,
+// tags [Content-Type: text/plain, App-Name: ar-bundles] and the data "hello arweave"
+const OWNER: &str = "03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8";
+const TAGS: &str = "0418436f6e74656e742d5479706514746578742f706c61696e104170702d4e616d651461722d62756e646c657300";
+const SIGNATURE_DATA: &str = "fdce1c3b640b252dcadfeb537978772431b9bd44cf0542a0a6ccd15357dc2a39f00eb08f91d62744bc0b9b8ccce49313";
+const ITEM: &str = concat!(
+    "02000f20f2e652654e50f82f888e32f2de4ba1db74699a6d9a3295d7070a7fec37d050923d59dbb78b5dcf1d6a7c02f3ff53f0abd4386c9805f4",
+    "1358a60846bc790b03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b801aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+    "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa01616e63686f722d616e63686f722d616e63686f722d616e63686f722d3030303102000000000000002e00",
+    "0000000000000418436f6e74656e742d5479706514746578742f706c61696e104170702d4e616d651461722d62756e646c65730068656c6c6f20",
+    "61727765617665"
+);
+const ITEM_ID: &str = "LrXr1wlyCwy-E3GeIzL4ZOnJM9m0IWxXTlPW_YcS76A";
+const ANCHOR: &[u8; 32] = b"anchor-anchor-anchor-anchor-0001";
+
+fn seed() -> Vec<u8> {
+    (0u8..32).collect()
+}
+
+#[test]
+fn matches_the_data_item_vector() {
+    let owner = hex::decode(OWNER).unwrap();
+    let tags = hex::decode(TAGS).unwrap();
+    let chunks = DeepHashChunk::List(vec![
+        DeepHashChunk::Blob(b"dataitem"),
+        DeepHashChunk::Blob(b"1"),
+        DeepHashChunk::Blob(b"2"),
+        DeepHashChunk::Blob(&owner),
+        DeepHashChunk::Blob(&[0xaa; 32]),
+        DeepHashChunk::Blob(ANCHOR),
+        DeepHashChunk::Blob(&tags),
+        DeepHashChunk::Blob(b"hello arweave")
+    ]);
+    assert_eq!(hex::encode(deep_hash(&chunks)), SIGNATURE_DATA);
+}
+
+#[test]
+fn matches_edge_case_vectors() {
+    assert_eq!(
+        hex::encode(deep_hash(&DeepHashChunk::Blob(&[]))),
+        "fbf00cc444f5fea9dc3bedf62a13fba8ae87e7445fc910567a23bec4eb82fadb1143c433069314d8362983dc3c2e4a38"
+    );
+    assert_eq!(
+        hex::encode(deep_hash(&DeepHashChunk::List(vec![]))),
+        "a69e7d37fdc7f040a9ec16aae84de24fab4a653dac4de0bd247e36bab9fe45d9289c5a04a893c95285812f5cefc9707a"
+    );
+    let nested = DeepHashChunk::List(vec![
+        DeepHashChunk::Blob(b"a"),
+        DeepHashChunk::List(vec![DeepHashChunk::Blob(b"b"), DeepHashChunk::Blob(b"")]),
+        DeepHashChunk::List(vec![])
+    ]);
+    assert_eq!(
+        hex::encode(deep_hash(&nested)),
+        "03371915016163f3e34c4326be972180d31f1157d39d536614593e8a6cb0b0d274ca236b330ebed27cb9e2eafa265711"
+    );
+}
+
+#[test]
+fn fixture_item_verifies() {
+    let binary = hex::decode(ITEM).unwrap();
+    let report = SignedDataItem::verify_bytes(&binary).unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.id.to_string(), ITEM_ID);
+
+    let item = SignedDataItem::from_bytes(binary).unwrap();
+    assert_eq!(hex::encode(get_signature_data(&item)), SIGNATURE_DATA);
+    assert_eq!(item.tags(), &[tag("Content-Type", "text/plain"), tag("App-Name", "ar-bundles")]);
+}
+
+#[test]
+fn signing_reproduces_the_fixture_byte_for_byte() {
+    let signer = Ed25519Signer::new(&seed()).unwrap();
+    let opts = DataItemCreateOptions {
+        target: Some(TxId::new([0xaa; 32])),
+        anchor: Some(Anchor::new(*ANCHOR)),
+        tags: Some(vec![tag("Content-Type", "text/plain"), tag("App-Name", "ar-bundles")])
+    };
+    let unsigned = create_data(Data::StringData("hello arweave".to_string()), &signer, Some(&opts)).unwrap();
+    assert_eq!(hex::encode(get_signature_data(&unsigned)), SIGNATURE_DATA);
+
+    let item = sign(unsigned, &signer).unwrap();
+    assert_eq!(hex::encode(item.get_raw()), ITEM);
+    assert_eq!(item.id().to_string(), ITEM_ID);
+}
\ No newline at end of file
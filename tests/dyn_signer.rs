@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use ar_bundles::{
+    ar_data_bundle::{bundle_and_sign, bundle_and_sign_async, sign},
+    ar_data_create::{create_data, create_data_async, Data, DataItemBuilder},
+    bundle::Bundle,
+    constants::SignatureConfig,
+    data_item::{SignedDataItem, UnsignedDataItem},
+    signing::{
+        chains::{ethereum_signer::EthereumSigner, solana_signer::SolanaSigner},
+        signer::Signer
+    }
+};
+use common::{arweave_signer, ed25519_signer};
+
+mod common;
+
+/// Picks the signer from configuration, the way a service choosing its chain at startup would
+fn signer_for(chain: &str) -> Box<dyn Signer + Send + Sync> {
+    match chain {
+        "arweave" => Box::new(arweave_signer()),
+        "ed25519" => Box::new(ed25519_signer()),
+        "ethereum" => Box::new(EthereumSigner::new(&[1; 32]).unwrap()),
+        "solana" => Box::new(SolanaSigner::new(&[2; 32]).unwrap()),
+        _ => panic!("unknown chain {}", chain)
+    }
+}
+
+fn unsigned_items(signer: &(dyn Signer + Send + Sync)) -> Vec<UnsignedDataItem> {
+    vec![
+        create_data(Data::StringData("first".to_string()), signer, None).unwrap(),
+        create_data(Data::BinaryData(vec![2; 64]), signer, None).unwrap()
+    ]
+}
+
+#[test]
+fn boxed_signers_create_sign_and_bundle() {
+    let chains = [
+        ("arweave", SignatureConfig::ARWEAVE),
+        ("ed25519", SignatureConfig::ED25519),
+        ("ethereum", SignatureConfig::ETHEREUM),
+        ("solana", SignatureConfig::SOLANA)
+    ];
+    for (chain, signature_type) in chains {
+        let signer = signer_for(chain);
+        assert_eq!(signer.signature_type(), signature_type);
+
+        let unsigned = create_data(Data::StringData(chain.to_string()), &signer, None).unwrap();
+        let item = sign(unsigned, &signer).unwrap();
+        assert!(SignedDataItem::verify_bytes(&item.get_raw()).unwrap().is_valid());
+
+        let item = DataItemBuilder::new().data_from_bytes(b"built".to_vec()).sign(&signer).unwrap();
+        assert_eq!(item.get_signature_type().unwrap(), signature_type);
+
+        let bundle = bundle_and_sign(unsigned_items(signer.as_ref()), &signer).unwrap();
+        for item in Bundle::from_bytes(bundle.get_raw()).unwrap().items().unwrap() {
+            assert!(SignedDataItem::verify_bytes(&item.get_raw()).unwrap().is_valid());
+            assert_eq!(item.get_signature_type().unwrap(), signature_type);
+        }
+    }
+}
+
+#[test]
+fn boxed_signers_are_shared_across_threads() {
+    let signer: Arc<Box<dyn Signer + Send + Sync>> = Arc::new(signer_for("ed25519"));
+    let handles: Vec<_> = (0..4u8).map(|i| {
+        let signer = Arc::clone(&signer);
+        std::thread::spawn(move || {
+            let unsigned = create_data(Data::BinaryData(vec![i; 8]), signer.as_ref(), None).unwrap();
+            sign(unsigned, signer.as_ref()).unwrap().get_raw()
+        })
+    }).collect();
+    for handle in handles {
+        assert!(SignedDataItem::verify_bytes(&handle.join().unwrap()).unwrap().is_valid());
+    }
+}
+
+#[tokio::test]
+async fn boxed_signers_work_with_the_async_apis() {
+    let signer = signer_for("solana");
+    let unsigned = create_data_async(Data::StringData("async".to_string()), &signer, None).await.unwrap();
+    let bundle = bundle_and_sign_async(vec![unsigned], &signer).await.unwrap();
+    let items = Bundle::from_bytes(bundle.get_raw()).unwrap().items().unwrap();
+    assert_eq!(items.len(), 1);
+    assert!(SignedDataItem::verify_bytes(&items[0].get_raw()).unwrap().is_valid());
+}
\ No newline at end of file
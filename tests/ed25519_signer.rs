@@ -0,0 +1,70 @@
+use ar_bundles::{
+    ar_data_bundle::sign,
+    ar_data_create::{create_data, Data},
+    constants::SignatureConfig,
+    data_item::SignedDataItem,
+    errors::ArBundleErrors,
+    signing::{chains::ed25519_signer::Ed25519Signer, signer::Signer}
+};
+
+// RFC 8032 section 7.1, test 1
+const SEED: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+const PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+const EMPTY_MESSAGE_SIGNATURE: &str = concat!(
+    "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155",
+    "5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+);
+
+fn keypair() -> Vec<u8> {
+    [hex::decode(SEED).unwrap(), hex::decode(PUBLIC_KEY).unwrap()].concat()
+}
+
+#[test]
+fn seed_matches_rfc8032() {
+    let signer = Ed25519Signer::from_seed(&hex::decode(SEED).unwrap()).unwrap();
+    assert_eq!(hex::encode(signer.get_public_key()), PUBLIC_KEY);
+    assert_eq!(hex::encode(signer.sign(&[]).unwrap()), EMPTY_MESSAGE_SIGNATURE);
+}
+
+#[test]
+fn seed_and_keypair_load_the_same_key() {
+    let from_seed = Ed25519Signer::new(&hex::decode(SEED).unwrap()).unwrap();
+    let from_keypair = Ed25519Signer::new(&keypair()).unwrap();
+    assert_eq!(from_seed.get_public_key(), from_keypair.get_public_key());
+    assert_eq!(Ed25519Signer::from_keypair(&keypair()).unwrap().get_public_key(), from_seed.get_public_key());
+    assert_eq!(from_seed.sign(b"message").unwrap(), from_keypair.sign(b"message").unwrap());
+}
+
+#[test]
+fn rejects_keypairs_whose_public_half_does_not_match() {
+    let mut keypair = keypair();
+    keypair[63] ^= 1;
+    assert!(matches!(Ed25519Signer::new(&keypair), Err(ArBundleErrors::KeyCreationFailed(_))));
+
+    let mut keypair = hex::decode(SEED).unwrap();
+    keypair.extend_from_slice(&[9; 32]);
+    assert!(matches!(Ed25519Signer::from_keypair(&keypair), Err(ArBundleErrors::KeyCreationFailed(_))));
+}
+
+#[test]
+fn rejects_keys_of_the_wrong_length() {
+    for length in [0, 31, 33, 63, 65] {
+        assert!(matches!(Ed25519Signer::new(&vec![1; length]), Err(ArBundleErrors::KeyCreationFailed(_))));
+    }
+    assert!(Ed25519Signer::from_seed(&keypair()).is_err());
+    assert!(Ed25519Signer::from_keypair(&hex::decode(SEED).unwrap()).is_err());
+}
+
+#[test]
+fn signed_items_are_type_2_and_verify() {
+    for signer in [Ed25519Signer::new(&hex::decode(SEED).unwrap()).unwrap(), Ed25519Signer::new(&keypair()).unwrap()] {
+        let unsigned = create_data(Data::StringData("ed25519".to_string()), &signer, None).unwrap();
+        let binary = sign(unsigned, &signer).unwrap().get_raw();
+        assert_eq!(&binary[..2], &[2, 0]);
+
+        let item = SignedDataItem::from_bytes(binary.clone()).unwrap();
+        assert_eq!(item.get_signature_type().unwrap(), SignatureConfig::ED25519);
+        assert_eq!(hex::encode(item.raw_owner()), PUBLIC_KEY);
+        assert!(SignedDataItem::verify_bytes(&binary).unwrap().is_valid());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+use ar_bundles::{
+    ar_data_bundle::sign,
+    ar_data_create::{create_data, Data},
+    constants::SignatureConfig,
+    data_item::SignedDataItem,
+    signing::{chains::ethereum_signer::EthereumSigner, signer::Signer}
+};
+
+// Hardhat's first dev account, signing the bytes 0x00..0x2f with EIP-191 personal_sign
+const PRIVATE_KEY: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+const OWNER: &str = concat!(
+    "048318535b54105d4a7aae60c08fc45f9687181b4fdfc625bd1a753fa7397fed75",
+    "3547f11ca8696646f2f3acb08e31016afac23e630c5d11f59f61fef57b0d2aa5"
+);
+const ADDRESS: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+const SIGNATURE: &str = concat!(
+    "44699d9a61e9ca2876209803031f101651ac40e0da583d5d04390cb3c91f300e",
+    "4c87da36bbf8d631800e7dc578eebecdc8771ada61e2c84157d3db5f5121f306",
+    "1c"
+);
+
+fn message() -> Vec<u8> {
+    (0u8..48).collect()
+}
+
+#[test]
+fn matches_the_known_vector() {
+    let signer = EthereumSigner::from_hex(PRIVATE_KEY).unwrap();
+    assert_eq!(hex::encode(signer.get_public_key()), OWNER);
+    assert_eq!(signer.address(), ADDRESS);
+    assert_eq!(hex::encode(signer.sign(&message()).unwrap()), SIGNATURE);
+}
+
+#[test]
+fn hex_keys_load_with_or_without_prefix() {
+    let prefixed = EthereumSigner::from_hex(&format!("0x{}", PRIVATE_KEY)).unwrap();
+    let raw = EthereumSigner::new(&hex::decode(PRIVATE_KEY).unwrap()).unwrap();
+    assert_eq!(prefixed.get_public_key(), raw.get_public_key());
+    assert!(EthereumSigner::from_hex("0xnot-hex").is_err());
+    assert!(EthereumSigner::new(&[0; 32]).is_err());
+}
+
+#[test]
+fn signed_items_verify_and_report_the_address() {
+    let signer = EthereumSigner::from_hex(PRIVATE_KEY).unwrap();
+    let unsigned = create_data(Data::StringData("ethereum".to_string()), &signer, None).unwrap();
+    let binary = sign(unsigned, &signer).unwrap().get_raw();
+    assert_eq!(&binary[..2], &[3, 0]);
+    assert!(SignedDataItem::verify_bytes(&binary).unwrap().is_valid());
+
+    let item = SignedDataItem::from_bytes(binary).unwrap();
+    assert_eq!(item.get_signature_type().unwrap(), SignatureConfig::ETHEREUM);
+    assert_eq!(item.owner_address().unwrap(), ADDRESS);
+}
\ No newline at end of file
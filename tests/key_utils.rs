@@ -0,0 +1,79 @@
+use ar_bundles::{
+    ar_data_bundle::sign,
+    ar_data_create::{create_data, Data},
+    data_item::SignedDataItem,
+    errors::ArBundleErrors,
+    interface_jwk::JWKInterface,
+    key_utils::CryptoDriver,
+    signing::{chains::arweave_signer::ArweaveSigner, signer::Signer}
+};
+use common::ARWEAVE_WALLET;
+
+mod common;
+
+// the wallet in tests/fixtures/arweave_wallet.json, exported by the python cryptography package
+const WALLET_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/arweave_wallet.json");
+const PKCS8_PEM: &str = include_str!("fixtures/arweave_wallet_pkcs8.pem");
+const PKCS1_PEM: &str = include_str!("fixtures/arweave_wallet_pkcs1.pem");
+const PKCS8_DER: &[u8] = include_bytes!("fixtures/arweave_wallet_pkcs8.der");
+
+fn wallet() -> JWKInterface {
+    serde_json::from_str(ARWEAVE_WALLET).unwrap()
+}
+
+fn assert_signs_verifiable_items(signer: &ArweaveSigner) {
+    let unsigned = create_data(Data::StringData("arweave".to_string()), signer, None).unwrap();
+    let binary = sign(unsigned, signer).unwrap().get_raw();
+    assert_eq!(&binary[..2], &[1, 0]);
+    assert!(SignedDataItem::verify_bytes(&binary).unwrap().is_valid());
+}
+
+#[test]
+fn keypair_files_are_read_as_jwk() {
+    let from_file = CryptoDriver::from_keypair_path(WALLET_PATH).unwrap();
+    let from_jwk = CryptoDriver::from_jwk_interface(&wallet()).unwrap();
+    assert_eq!(from_file, from_jwk);
+
+    let driver = CryptoDriver::new(WALLET_PATH).unwrap();
+    assert_eq!(driver.get_public_key().unwrap(), wallet().n);
+}
+
+#[test]
+fn every_key_format_loads_the_same_wallet() {
+    let signers = [
+        ArweaveSigner::from_jwk_str(ARWEAVE_WALLET).unwrap(),
+        ArweaveSigner::from_keypair_path(WALLET_PATH).unwrap(),
+        ArweaveSigner::from_pem(PKCS8_PEM).unwrap(),
+        ArweaveSigner::from_pem(PKCS1_PEM).unwrap(),
+        ArweaveSigner::from_pkcs8_der(PKCS8_DER).unwrap()
+    ];
+    for signer in signers.iter() {
+        assert_eq!(signer.public_key(), wallet().n);
+        assert_eq!(signer.get_public_key().len(), 512);
+        assert_signs_verifiable_items(signer);
+    }
+    assert_eq!(signers[1].get_keypair_path(), WALLET_PATH);
+    assert_eq!(signers[0].get_keypair_path(), "");
+}
+
+#[test]
+fn keys_without_primes_are_recovered() {
+    let mut jwk = wallet();
+    jwk.p = None;
+    jwk.q = None;
+    let recovered = CryptoDriver::from_jwk_interface(&jwk).unwrap();
+    assert_eq!(recovered, CryptoDriver::from_jwk_interface(&wallet()).unwrap());
+}
+
+#[test]
+fn unusable_keys_are_rejected() {
+    assert!(matches!(CryptoDriver::from_keypair_path("/nonexistent/wallet.json"), Err(ArBundleErrors::ReadKeyPairFileFailed(_))));
+    assert!(matches!(CryptoDriver::from_keypair_path(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")), Err(ArBundleErrors::KeyCreationFailed(_))));
+
+    let mut public_only = wallet();
+    public_only.d = None;
+    assert!(matches!(CryptoDriver::from_jwk_interface(&public_only), Err(ArBundleErrors::KeyCreationFailed(_))));
+    assert!(matches!(ArweaveSigner::from_jwk_str("{}"), Err(ArBundleErrors::KeyCreationFailed(_))));
+    assert!(matches!(ArweaveSigner::from_pem("not a pem"), Err(ArBundleErrors::KeyCreationFailed(_))));
+    assert!(matches!(ArweaveSigner::from_pkcs8_der(&PKCS8_DER[1..]), Err(ArBundleErrors::KeyCreationFailed(_))));
+}
\ No newline at end of file
@@ -0,0 +1,231 @@
+use ar_bundles::{
+    bundle::Bundle,
+    data_item::{SignedDataItem, UnsignedDataItem},
+    data_item_ref::DataItemRef,
+    errors::ArBundleErrors,
+    tags::deserialize_tags,
+    utils::{byte_array_to_long, long_to_32_byte_array, long_to_8_byte_array}
+};
+use common::raw_item;
+
+mod common;
+
+fn valid_item() -> Vec<u8> {
+    let mut target = vec![1];
+    target.extend_from_slice(&[3u8; 32]);
+    raw_item(&target, &[0], 1, &[2, 2, b'a', 2, b'b', 0], b"data")
+}
+
+const TAG_BYTES_OFFSET: usize = 2 + 64 + 32 + 33 + 1 + 8;
+
+#[test]
+fn valid_item_parses() {
+    let item = SignedDataItem::from_bytes(valid_item()).unwrap();
+    assert_eq!(item.tags().len(), 1);
+    assert_eq!(item.raw_data(), b"data");
+}
+
+#[test]
+fn empty_and_short_inputs_are_truncated() {
+    for len in [0, 1, 79] {
+        assert!(matches!(
+            DataItemRef::new(&vec![2; len]),
+            Err(ArBundleErrors::Truncated { field: "header", .. })
+        ));
+    }
+}
+
+#[test]
+fn unknown_signature_type_is_rejected() {
+    let mut item = valid_item();
+    item[0] = 99;
+    assert!(matches!(SignedDataItem::from_bytes(item), Err(ArBundleErrors::SignatureConfigTypeNotFound)));
+}
+
+#[test]
+fn owner_longer_than_input_is_truncated() {
+    // arweave signatures and owners are 512 bytes each
+    let mut item = vec![1, 0];
+    item.extend_from_slice(&[1u8; 600]);
+    assert!(matches!(
+        DataItemRef::new(&item),
+        Err(ArBundleErrors::Truncated { field: "owner", offset: 514 })
+    ));
+}
+
+#[test]
+fn invalid_presence_bytes_are_rejected() {
+    let item = raw_item(&[2], &[0], 0, &[], &[0; 16]);
+    assert!(matches!(
+        DataItemRef::new(&item),
+        Err(ArBundleErrors::InvalidPresenceByte { field: "target", value: 2 })
+    ));
+
+    let item = raw_item(&[0], &[255], 0, &[], &[0; 16]);
+    assert!(matches!(
+        DataItemRef::new(&item),
+        Err(ArBundleErrors::InvalidPresenceByte { field: "anchor", value: 255 })
+    ));
+}
+
+#[test]
+fn present_target_without_its_bytes_is_truncated() {
+    let mut item = raw_item(&[0], &[0], 0, &[], &[]);
+    item.truncate(2 + 64 + 32);
+    item.push(1);
+    item.extend_from_slice(&[0; 20]);
+    assert!(matches!(
+        DataItemRef::new(&item),
+        Err(ArBundleErrors::Truncated { field: "target", offset: 98 })
+    ));
+}
+
+#[test]
+fn oversized_tag_byte_counts_are_rejected() {
+    for tag_bytes in [[0, 0x10, 0, 0, 0, 0, 0, 1], [0xff; 8]] {
+        let mut item = valid_item();
+        item[TAG_BYTES_OFFSET..TAG_BYTES_OFFSET + 8].copy_from_slice(&tag_bytes);
+        assert!(matches!(
+            DataItemRef::new(&item),
+            Err(ArBundleErrors::LengthOutOfRange { field: "tag byte count", .. })
+        ));
+    }
+}
+
+#[test]
+fn tag_bytes_past_the_end_are_truncated() {
+    let mut item = raw_item(&[0], &[0], 1, &[], &[]);
+    item[TAG_BYTES_OFFSET - 32..TAG_BYTES_OFFSET - 24].copy_from_slice(&long_to_8_byte_array(100));
+    assert!(matches!(
+        DataItemRef::new(&item),
+        Err(ArBundleErrors::Truncated { field: "tags", .. })
+    ));
+}
+
+#[test]
+fn malformed_avro_tags_are_rejected() {
+    let cases: Vec<Vec<u8>> = vec![
+        vec![0x80],                                // varint never terminates
+        vec![0xff; 11],                            // varint longer than 64 bits
+        vec![2, 3],                                // string with negative length
+        vec![2, 40, b'a'],                         // string longer than the buffer
+        vec![2, 2, 0xc3, 2, b'b', 0],              // invalid utf-8
+        vec![2, 2, b'a'],                          // value missing
+        vec![1, 0, 0],                             // negative block count whose items are missing
+        vec![0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01] // block count of i64::MIN
+    ];
+    for tags in cases {
+        assert!(deserialize_tags(tags.clone()).is_err(), "{:?} decoded", tags);
+        let item = raw_item(&[0], &[0], 1, &tags, &[]);
+        assert!(SignedDataItem::from_bytes(item).is_err());
+    }
+}
+
+fn is_tag_count_mismatch<T>(result: Result<T, ArBundleErrors>, declared: i64, decoded: usize) -> bool {
+    matches!(result, Err(ArBundleErrors::TagCountMismatch { declared: d, decoded: n }) if d == declared && n == decoded)
+}
+
+#[test]
+fn tag_count_mismatch_fails_every_parse_path() {
+    let too_few = raw_item(&[0], &[0], 3, &[2, 2, b'a', 2, b'b', 0], &[]);
+    // a zero count does not skip decoding tag bytes that are there
+    let zero_count = raw_item(&[0], &[0], 0, &[2, 2, b'a', 2, b'b', 0], &[]);
+    for (item, declared) in [(too_few, 3), (zero_count, 0)] {
+        assert!(is_tag_count_mismatch(SignedDataItem::from_bytes(item.clone()), declared, 1));
+        assert!(is_tag_count_mismatch(UnsignedDataItem::from_bytes(item.clone()), declared, 1));
+        assert!(is_tag_count_mismatch(SignedDataItem::verify_bytes(&item), declared, 1));
+        assert!(is_tag_count_mismatch(DataItemRef::new(&item).unwrap().decode_tags(), declared, 1));
+
+        let mut binary = raw_bundle(std::slice::from_ref(&item));
+        let id = DataItemRef::new(&item).unwrap().id();
+        binary[64..96].copy_from_slice(id.as_bytes());
+        let bundle = Bundle::from_bytes(binary).unwrap();
+        assert!(is_tag_count_mismatch(bundle.items(), declared, 1));
+        assert!(is_tag_count_mismatch(bundle.get_by_index(0), declared, 1));
+        assert!(is_tag_count_mismatch(bundle.get_by_id(&id), declared, 1));
+    }
+
+    // no tags may be written as no bytes at all or as avsc's empty array
+    assert!(SignedDataItem::from_bytes(raw_item(&[0], &[0], 0, &[], &[])).is_ok());
+    assert!(SignedDataItem::from_bytes(raw_item(&[0], &[0], 0, &[0], &[])).is_ok());
+    assert!(is_tag_count_mismatch(SignedDataItem::from_bytes(raw_item(&[0], &[0], 1, &[], &[])), 1, 0));
+}
+
+#[test]
+fn unsigned_item_is_not_a_signed_item() {
+    let mut item = valid_item();
+    item[2..66].fill(0);
+    assert!(matches!(SignedDataItem::from_bytes(item.clone()), Err(ArBundleErrors::DataItemNotSigned)));
+    assert!(UnsignedDataItem::from_bytes(item).is_ok());
+}
+
+#[test]
+fn every_prefix_and_byte_flip_fails_cleanly() {
+    let item = valid_item();
+    for len in 0..item.len() {
+        let _ = SignedDataItem::verify_bytes(&item[..len]);
+    }
+    for index in 0..item.len() {
+        for value in [0u8, 1, 0x7f, 0x80, 0xff] {
+            let mut flipped = item.clone();
+            flipped[index] = value;
+            let _ = SignedDataItem::verify_bytes(&flipped);
+        }
+    }
+}
+
+#[test]
+fn byte_array_to_long_handles_edges() {
+    assert_eq!(byte_array_to_long(&[]), Some(0));
+    assert_eq!(byte_array_to_long(&[0xff; 7]), Some(0x00ff_ffff_ffff_ffff));
+    assert_eq!(byte_array_to_long(&[0xff; 8]), None);
+    assert_eq!(byte_array_to_long(&long_to_32_byte_array(5)), Some(5));
+}
+
+fn raw_bundle(items: &[Vec<u8>]) -> Vec<u8> {
+    let mut bytes = long_to_32_byte_array(items.len() as i64).to_vec();
+    for item in items {
+        bytes.extend_from_slice(&long_to_32_byte_array(item.len() as i64));
+        bytes.extend_from_slice(&[0u8; 32]);
+    }
+    for item in items {
+        bytes.extend_from_slice(item);
+    }
+    bytes
+}
+
+#[test]
+fn malformed_bundles_are_rejected() {
+    assert!(Bundle::from_bytes(raw_bundle(&[valid_item()])).is_ok());
+
+    assert!(matches!(
+        Bundle::from_bytes(vec![0; 31]),
+        Err(ArBundleErrors::Truncated { field: "item count", .. })
+    ));
+
+    let mut too_many = raw_bundle(&[valid_item()]);
+    too_many[0] = 200;
+    assert!(matches!(Bundle::from_bytes(too_many), Err(ArBundleErrors::Truncated { field: "item headers", .. })));
+
+    let mut huge_count = raw_bundle(&[]);
+    huge_count[..32].fill(0xff);
+    assert!(matches!(Bundle::from_bytes(huge_count), Err(ArBundleErrors::LengthOutOfRange { field: "item count", .. })));
+
+    let mut huge_size = raw_bundle(&[valid_item()]);
+    huge_size[32..64].fill(0xff);
+    assert!(matches!(Bundle::from_bytes(huge_size), Err(ArBundleErrors::LengthOutOfRange { field: "item size", .. })));
+
+    let mut truncated = raw_bundle(&[valid_item()]);
+    truncated.pop();
+    assert!(matches!(Bundle::from_bytes(truncated), Err(ArBundleErrors::Truncated { field: "item", .. })));
+
+    let mut trailing = raw_bundle(&[valid_item()]);
+    trailing.push(0);
+    assert!(matches!(Bundle::from_bytes(trailing), Err(ArBundleErrors::TrailingBytes { .. })));
+
+    let mut bad_item = valid_item();
+    bad_item[0] = 99;
+    let bundle = Bundle::from_bytes(raw_bundle(&[bad_item])).unwrap();
+    assert!(bundle.get_by_index(0).is_err());
+    assert!(bundle.item_refs().is_err());
+}
\ No newline at end of file
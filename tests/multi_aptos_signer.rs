@@ -0,0 +1,105 @@
+use ar_bundles::{
+    ar_data_bundle::{apply_signature, sign},
+    ar_data_base::get_signature_data,
+    ar_data_create::{create_data, Data},
+    constants::SignatureConfig,
+    data_item::SignedDataItem,
+    errors::ArBundleErrors,
+    signing::{
+        chains::{ed25519_signer::Ed25519Signer, multi_aptos_signer::{pack_multi_aptos_signature, MultiAptosSigner}},
+        constants::get_index_to_type,
+        signer::Signer
+    }
+};
+
+// ed25519 public keys of the seeds [1; 32], [2; 32] and [3; 32], and the address of the 2 of 3 account
+const PUBLIC_KEYS: [&str; 3] = [
+    "8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c",
+    "8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394",
+    "ed4928c628d1c2c6eae90338905995612959273a5c63f93636c14614ac8737d1"
+];
+const ADDRESS: &str = "0xe103d0e6e67b017524bebf94ae151df6a70c6f354178a88a9a3865bcafabfdb4";
+const BITMAP_START: usize = 32 * 64;
+
+fn account(signing_seeds: &[u8]) -> MultiAptosSigner {
+    let mut builder = MultiAptosSigner::builder().threshold(2);
+    for public_key in PUBLIC_KEYS {
+        builder = builder.public_key(hex::decode(public_key).unwrap().try_into().unwrap());
+    }
+    for seed in signing_seeds {
+        builder = builder.signing_key(&[*seed; 32]);
+    }
+    builder.build().unwrap()
+}
+
+fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    get_index_to_type()[&SignatureConfig::MULTIAPTOS](pk, message, signature)
+}
+
+#[test]
+fn bitmap_bits_start_at_the_most_significant_bit() {
+    let packed = pack_multi_aptos_signature(&[(0, vec![1; 64]), (9, vec![2; 64])]).unwrap();
+    assert_eq!(packed.len(), BITMAP_START + 4);
+    assert_eq!(&packed[BITMAP_START..], &[0x80, 0x40, 0, 0]);
+    assert_eq!(&packed[..64], &[1; 64]);
+    assert_eq!(&packed[9 * 64..10 * 64], &[2; 64]);
+    assert!(packed[64..9 * 64].iter().all(|byte| *byte == 0));
+
+    let packed = pack_multi_aptos_signature(&[(31, vec![3; 64])]).unwrap();
+    assert_eq!(&packed[BITMAP_START..], &[0, 0, 0, 0x01]);
+}
+
+#[test]
+fn packing_rejects_duplicate_and_out_of_range_indices() {
+    assert!(matches!(
+        pack_multi_aptos_signature(&[(1, vec![1; 64]), (1, vec![2; 64])]),
+        Err(ArBundleErrors::SignatureAttemptFailed)
+    ));
+    assert!(matches!(pack_multi_aptos_signature(&[(32, vec![1; 64])]), Err(ArBundleErrors::SignatureAttemptFailed)));
+    assert!(matches!(pack_multi_aptos_signature(&[(0, vec![1; 63])]), Err(ArBundleErrors::SignatureAttemptFailed)));
+}
+
+#[test]
+fn address_matches_the_known_vector() {
+    let signer = account(&[1, 3]);
+    assert_eq!(signer.address(), ADDRESS);
+    assert_eq!(signer.threshold(), 2);
+
+    let owner = signer.get_public_key();
+    assert_eq!(owner.len(), 32 * 32 + 1);
+    assert_eq!(owner[owner.len() - 1], 2);
+}
+
+#[test]
+fn signed_items_verify_and_report_the_address() {
+    let signer = account(&[3, 1]);
+    let unsigned = create_data(Data::StringData("multi aptos".to_string()), &signer, None).unwrap();
+    let binary = sign(unsigned, &signer).unwrap().get_raw();
+    assert!(SignedDataItem::verify_bytes(&binary).unwrap().is_valid());
+
+    let item = SignedDataItem::from_bytes(binary).unwrap();
+    assert_eq!(item.get_signature_type().unwrap(), SignatureConfig::MULTIAPTOS);
+    assert_eq!(&item.raw_signature()[BITMAP_START..], &[0xa0, 0, 0, 0]);
+    assert_eq!(item.owner_address().unwrap(), ADDRESS);
+}
+
+#[test]
+fn signatures_below_the_threshold_do_not_verify() {
+    let signer = account(&[2]);
+    let unsigned = create_data(Data::StringData("multi aptos".to_string()), &signer, None).unwrap();
+    let message = get_signature_data(&unsigned);
+    assert!(matches!(signer.sign(&message), Err(ArBundleErrors::ThresholdNotMet(1, 2))));
+
+    let partial = Ed25519Signer::new(&[2; 32]).unwrap().sign(&message).unwrap();
+    let packed = pack_multi_aptos_signature(&[(1, partial.clone())]).unwrap();
+    assert!(!verify(&signer.get_public_key(), &message, &packed));
+    assert!(matches!(apply_signature(unsigned, &packed), Err(ArBundleErrors::InvalidSignature)));
+
+    let other = Ed25519Signer::new(&[3; 32]).unwrap().sign(&message).unwrap();
+    let packed = signer.combine_signatures(&message, &[(1, partial.clone()), (2, other)]).unwrap();
+    assert!(verify(&signer.get_public_key(), &message, &packed));
+
+    // a signature in the wrong slot invalidates the whole set
+    let misplaced = pack_multi_aptos_signature(&[(0, partial.clone()), (1, partial)]).unwrap();
+    assert!(!verify(&signer.get_public_key(), &message, &misplaced));
+}
\ No newline at end of file
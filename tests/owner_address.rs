@@ -0,0 +1,74 @@
+use ar_bundles::{
+    ar_data_bundle::sign,
+    ar_data_create::{create_data, Data},
+    constants::SignatureConfig,
+    data_item::SignedDataItem,
+    signing::{
+        chains::{
+            ed25519_signer::Ed25519Signer,
+            ethereum_signer::EthereumSigner,
+            injected_aptos_signer::InjectedAptosSigner,
+            multi_aptos_signer::MultiAptosSigner,
+            solana_signer::SolanaSigner,
+            typed_ethereum_signer::TypedEthereumSigner
+        },
+        signer::Signer
+    }
+};
+use common::arweave_signer;
+
+mod common;
+
+// RFC 8032 test 1 key, used for the ed25519 based types
+const ED25519_SEED: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+// Hardhat's first dev account, used for both Ethereum types
+const ETHEREUM_KEY: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+fn owner_address<T: Signer + ?Sized>(signer: &T) -> String {
+    let unsigned = create_data(Data::StringData("owner".to_string()), signer, None).unwrap();
+    let item = SignedDataItem::from_bytes(sign(unsigned, signer).unwrap().get_raw()).unwrap();
+    assert_eq!(item.get_signature_type().unwrap(), signer.signature_type());
+    item.owner_address().unwrap()
+}
+
+#[test]
+fn addresses_match_known_vectors() {
+    let seed = hex::decode(ED25519_SEED).unwrap();
+    // 2 of 3 account over the ed25519 keys of the seeds [1; 32], [2; 32] and [3; 32]
+    let multi_aptos = MultiAptosSigner::builder()
+        .public_key(hex::decode("8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c").unwrap().try_into().unwrap())
+        .public_key(hex::decode("8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394").unwrap().try_into().unwrap())
+        .public_key(hex::decode("ed4928c628d1c2c6eae90338905995612959273a5c63f93636c14614ac8737d1").unwrap().try_into().unwrap())
+        .threshold(2)
+        .signing_key(&[1; 32])
+        .signing_key(&[2; 32])
+        .build()
+        .unwrap();
+
+    let cases: Vec<(SignatureConfig, Box<dyn Signer>, &str)> = vec![
+        (SignatureConfig::ARWEAVE, Box::new(arweave_signer()), "IcKOjX0GRTSI4EX4lFxV4UnxNVhypnW_kBFwerLI3Gg"),
+        (SignatureConfig::ED25519, Box::new(Ed25519Signer::new(&seed).unwrap()), "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z"),
+        (SignatureConfig::ETHEREUM, Box::new(EthereumSigner::from_hex(ETHEREUM_KEY).unwrap()), "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"),
+        (SignatureConfig::SOLANA, Box::new(SolanaSigner::new(&seed).unwrap()), "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z"),
+        (
+            SignatureConfig::INJECTEDAPTOS,
+            Box::new(InjectedAptosSigner::new(&seed).unwrap()),
+            "0x63c5215e87770d17b9f4cd47c777e322f4eb152cfd2054c1080fd9d57c48913b"
+        ),
+        (
+            SignatureConfig::MULTIAPTOS,
+            Box::new(multi_aptos),
+            "0xe103d0e6e67b017524bebf94ae151df6a70c6f354178a88a9a3865bcafabfdb4"
+        ),
+        (
+            SignatureConfig::TYPEDETHEREUM,
+            Box::new(TypedEthereumSigner::from_hex(ETHEREUM_KEY).unwrap()),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+        )
+    ];
+    assert_eq!(cases.len(), 7);
+    for (signature_type, signer, expected) in cases {
+        assert_eq!(signer.signature_type(), signature_type);
+        assert_eq!(owner_address(signer.as_ref()), expected, "{:?}", signature_type);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,144 @@
+use ar_bundles::{
+    ar_data_bundle::{bundle_and_sign_async, sign_async},
+    ar_data_create::{create_data_async, Data},
+    bundle::Bundle,
+    constants::SignatureConfig,
+    data_item::SignedDataItem,
+    errors::ArBundleErrors,
+    signing::{chains::ed25519_signer::Ed25519Signer, remote_signer::RemoteSigner, signer::{AsyncSigner, Signer}}
+};
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{body_partial_json, method},
+    Mock, MockServer, Request, Respond, ResponseTemplate
+};
+use common::ed25519_signer;
+
+mod common;
+
+/// JSON-RPC signing service holding an ed25519 key, answering the way `RemoteSigner` expects
+struct SigningService(Ed25519Signer);
+
+impl Respond for SigningService {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let request: Value = serde_json::from_slice(&request.body).unwrap();
+        let result = match request["method"].as_str() {
+            Some("getPublicKey") => Signer::get_public_key(&self.0),
+            Some("sign") => {
+                let message = base64_url::decode(request["params"]["message"].as_str().unwrap()).unwrap();
+                Signer::sign(&self.0, &message).unwrap()
+            },
+            _ => return rpc_error(&request, -32601, "method not found")
+        };
+        ResponseTemplate::new(200).set_body_json(json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "result": base64_url::encode(&result)
+        }))
+    }
+}
+
+fn rpc_error(request: &Value, code: i64, message: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "jsonrpc": "2.0",
+        "id": request["id"],
+        "error": { "code": code, "message": message }
+    }))
+}
+
+fn rpc_result(result: Value) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({ "jsonrpc": "2.0", "id": 1, "result": result }))
+}
+
+async fn signing_service() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST")).respond_with(SigningService(ed25519_signer())).mount(&server).await;
+    server
+}
+
+fn remote_failure<T>(result: Result<T, ArBundleErrors>) -> String {
+    match result {
+        Err(ArBundleErrors::RemoteSignerFailed(message)) => message,
+        Err(e) => panic!("expected a remote signer failure, got {}", e),
+        Ok(_) => panic!("expected a remote signer failure")
+    }
+}
+
+#[tokio::test]
+async fn creates_signs_and_bundles_through_the_service() {
+    let server = signing_service().await;
+    let signer = RemoteSigner::new(&server.uri(), SignatureConfig::ED25519).unwrap();
+    assert_eq!(signer.get_public_key().await.unwrap(), Signer::get_public_key(&ed25519_signer()));
+
+    let unsigned = create_data_async(Data::StringData("remote".to_string()), &signer, None).await.unwrap();
+    let item = sign_async(unsigned, &signer).await.unwrap();
+    assert!(SignedDataItem::verify_bytes(&item.get_raw()).unwrap().is_valid());
+
+    let items = vec![
+        create_data_async(Data::StringData("first".to_string()), &signer, None).await.unwrap(),
+        create_data_async(Data::BinaryData(vec![1; 100]), &signer, None).await.unwrap()
+    ];
+    let bundle = bundle_and_sign_async(items, &signer).await.unwrap();
+    let bundle = Bundle::from_bytes(bundle.get_raw()).unwrap();
+    for item in bundle.items().unwrap() {
+        assert!(SignedDataItem::verify_bytes(&item.get_raw()).unwrap().is_valid());
+        assert_eq!(item.raw_owner(), Signer::get_public_key(&ed25519_signer()).as_slice());
+    }
+
+    // the public key is fetched once, every item costs one sign call
+    let requests = server.received_requests().await.unwrap();
+    let calls = |name: &str| requests.iter()
+        .filter(|request| serde_json::from_slice::<Value>(&request.body).unwrap()["method"] == name)
+        .count();
+    assert_eq!(calls("getPublicKey"), 1);
+    assert_eq!(calls("sign"), 3);
+}
+
+#[tokio::test]
+async fn json_rpc_errors_are_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(|request: &Request| rpc_error(&serde_json::from_slice(&request.body).unwrap(), -32000, "key is locked"))
+        .mount(&server)
+        .await;
+    let signer = RemoteSigner::new(&server.uri(), SignatureConfig::ED25519).unwrap();
+
+    let message = remote_failure(signer.get_public_key().await);
+    assert!(message.contains("getPublicKey returned error -32000: key is locked"), "{}", message);
+    let message = remote_failure(AsyncSigner::sign(&signer, &[1; 48]).await);
+    assert!(message.contains("sign returned error -32000"), "{}", message);
+}
+
+#[tokio::test]
+async fn malformed_results_are_rejected() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "getPublicKey" })))
+        .respond_with(rpc_result(json!("not base64url!")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "method": "sign" })))
+        .respond_with(rpc_result(json!(base64_url::encode(&[1; 10]))))
+        .mount(&server)
+        .await;
+    let signer = RemoteSigner::new(&server.uri(), SignatureConfig::ED25519).unwrap();
+
+    let message = remote_failure(signer.get_public_key().await);
+    assert!(message.contains("getPublicKey result is not base64url"), "{}", message);
+    let message = remote_failure(AsyncSigner::sign(&signer, &[1; 48]).await);
+    assert!(message.contains("sign result must be 64 bytes but was 10"), "{}", message);
+}
+
+#[tokio::test]
+async fn non_string_results_and_http_failures_are_rejected() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST")).respond_with(rpc_result(json!(42))).mount(&server).await;
+    let signer = RemoteSigner::new(&server.uri(), SignatureConfig::ED25519).unwrap();
+    assert!(remote_failure(signer.get_public_key().await).contains("result is not a string"));
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST")).respond_with(ResponseTemplate::new(500)).mount(&server).await;
+    let signer = RemoteSigner::new(&server.uri(), SignatureConfig::ED25519).unwrap();
+    remote_failure(signer.get_public_key().await);
+}
\ No newline at end of file
@@ -0,0 +1,106 @@
+use ar_bundles::{
+    ar_data_bundle::{apply_signature, bundle},
+    bundle::Bundle,
+    bundle_item::BundleItemFn,
+    constants::{get_sig_config, SignatureConfig},
+    data_item::{SignedDataItem, UnsignedDataItem},
+    signing::{
+        chains::{
+            ethereum_signer::EthereumSigner,
+            injected_aptos_signer::InjectedAptosSigner,
+            multi_aptos_signer::MultiAptosSigner,
+            solana_signer::SolanaSigner,
+            typed_ethereum_signer::TypedEthereumSigner
+        },
+        constants::get_index_to_type,
+        signer::Signer
+    }
+};
+use common::{arweave_signer, ed25519_signer};
+
+mod common;
+
+fn signers() -> Vec<Box<dyn Signer>> {
+    let multi_aptos = MultiAptosSigner::builder()
+        .public_key(ed25519_signer().get_public_key().try_into().unwrap())
+        .public_key([5; 32])
+        .threshold(1)
+        .signing_key(&[9; 32])
+        .build()
+        .unwrap();
+    vec![
+        Box::new(arweave_signer()),
+        Box::new(ed25519_signer()),
+        Box::new(EthereumSigner::new(&[1; 32]).unwrap()),
+        Box::new(SolanaSigner::new(&[2; 32]).unwrap()),
+        Box::new(InjectedAptosSigner::new(&[3; 32]).unwrap()),
+        Box::new(multi_aptos),
+        Box::new(TypedEthereumSigner::new(&[4; 32]).unwrap())
+    ]
+}
+
+#[test]
+fn every_signature_type_is_registered() {
+    let registry = get_index_to_type();
+    assert_eq!(registry.len(), get_sig_config().len());
+    for signer in signers() {
+        assert!(registry.contains_key(&signer.signature_type()));
+    }
+}
+
+#[test]
+fn each_verifier_accepts_its_signer_and_rejects_altered_messages() {
+    let message = [0x5a; 48];
+    let mut altered = message;
+    altered[47] ^= 1;
+
+    for signer in signers() {
+        let signature_type = signer.signature_type();
+        let verify = get_index_to_type()[&signature_type];
+        let owner = signer.get_public_key();
+        let signature = signer.sign(&message).unwrap();
+        assert_eq!(owner.len(), signer.get_owner_length(), "{:?}", signature_type);
+        assert_eq!(signature.len(), signer.get_signature_length(), "{:?}", signature_type);
+
+        assert!(verify(&owner, &message, &signature), "{:?} rejected its own signature", signature_type);
+        assert!(!verify(&owner, &altered, &signature), "{:?} accepted an altered message", signature_type);
+        let mut tampered = signature.clone();
+        tampered[0] ^= 1;
+        assert!(!verify(&owner, &message, &tampered), "{:?} accepted a tampered signature", signature_type);
+    }
+}
+
+#[test]
+fn verifiers_do_not_accept_other_types_signatures() {
+    let message = [0x5a; 48];
+    let ed25519 = ed25519_signer();
+    let signature = ed25519.sign(&message).unwrap();
+    let owner = ed25519.get_public_key();
+    assert!(get_index_to_type()[&SignatureConfig::ED25519](&owner, &message, &signature));
+    // same key and signature bytes, but Aptos wallets sign an envelope around the message
+    assert!(!get_index_to_type()[&SignatureConfig::INJECTEDAPTOS](&owner, &message, &signature));
+    assert!(!get_index_to_type()[&SignatureConfig::ARWEAVE](&owner, &message, &signature));
+}
+
+// signed by node's crypto.sign with the maximum PSS salt, the way arweave-js signs data items
+const ARWEAVE_JS_ITEM: &[u8] = include_bytes!("fixtures/arweave_max_salt_item.bin");
+
+#[test]
+fn arweave_signatures_with_the_maximum_salt_are_accepted() {
+    let report = SignedDataItem::verify_bytes(ARWEAVE_JS_ITEM).unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.id.to_string(), "UKEgdNl7HfImrhEEtRbriX_pzzRFC7ctepxct6sg9SY");
+
+    let item = SignedDataItem::from_bytes(ARWEAVE_JS_ITEM.to_vec()).unwrap();
+    let mut unsigned = ARWEAVE_JS_ITEM.to_vec();
+    unsigned[2..514].fill(0);
+    let applied = apply_signature(UnsignedDataItem::from_bytes(unsigned).unwrap(), item.raw_signature()).unwrap();
+    assert_eq!(applied.get_raw(), ARWEAVE_JS_ITEM);
+
+    let bundle = Bundle::from_bytes(bundle(vec![item]).unwrap().get_raw()).unwrap();
+    assert!(bundle.items().unwrap().iter().all(|item| item.is_valid()));
+
+    let mut tampered = ARWEAVE_JS_ITEM.to_vec();
+    tampered[100] ^= 1;
+    assert!(!SignedDataItem::verify_bytes(&tampered).unwrap().is_valid());
+}
@@ -0,0 +1,89 @@
+use std::io::ErrorKind;
+use ar_bundles::{
+    ar_data_bundle::{apply_signing_request, get_signing_request, SigningRequest},
+    ar_data_create::{create_data, Data, DataItemCreateOptions},
+    data_item::SignedDataItem,
+    errors::ArBundleErrors,
+    signing::{
+        chains::{ed25519_signer::Ed25519Signer, ethereum_signer::EthereumSigner},
+        signer::Signer
+    }
+};
+use common::{ed25519_signer, tag};
+
+mod common;
+
+fn request(signer: &dyn Signer) -> SigningRequest {
+    let opts = DataItemCreateOptions { target: None, anchor: None, tags: Some(vec![tag("Content-Type", "text/plain")]) };
+    let unsigned = create_data(Data::StringData("sign me elsewhere".to_string()), signer, Some(&opts)).unwrap();
+    get_signing_request(&unsigned).unwrap()
+}
+
+/// What an offline signer does: only the request JSON crosses the boundary
+fn sign_externally(signer: &dyn Signer, json: &str) -> Vec<u8> {
+    let request = SigningRequest::from_json(json).unwrap();
+    signer.sign(&base64_url::decode(&request.signature_data).unwrap()).unwrap()
+}
+
+fn is_invalid_data(result: Result<SignedDataItem, ArBundleErrors>) -> bool {
+    matches!(result, Err(ArBundleErrors::IoFailure(e)) if e.kind() == ErrorKind::InvalidData)
+}
+
+#[test]
+fn requests_round_trip_through_json_and_an_external_signer() {
+    let signers: [Box<dyn Signer>; 2] = [Box::new(ed25519_signer()), Box::new(EthereumSigner::new(&[1; 32]).unwrap())];
+    for signer in signers.iter() {
+        let json = request(signer.as_ref()).to_json().unwrap();
+        let signature = sign_externally(signer.as_ref(), &json);
+
+        let item = apply_signing_request(&SigningRequest::from_json(&json).unwrap(), &signature).unwrap();
+        let report = SignedDataItem::verify_bytes(&item.get_raw()).unwrap();
+        assert!(report.is_valid());
+        assert_eq!(report.signature_type, signer.signature_type());
+        assert_eq!(item.raw_data(), b"sign me elsewhere");
+        assert_eq!(item.raw_owner(), signer.get_public_key().as_slice());
+    }
+}
+
+#[test]
+fn requests_that_disagree_with_their_item_are_rejected() {
+    let signer = ed25519_signer();
+    let original = request(&signer);
+    let signature = sign_externally(&signer, &original.to_json().unwrap());
+    let tampered = |change: fn(&mut SigningRequest)| {
+        let mut request = SigningRequest::from_json(&original.to_json().unwrap()).unwrap();
+        change(&mut request);
+        apply_signing_request(&request, &signature)
+    };
+
+    assert!(tampered(|_| {}).is_ok());
+    assert!(is_invalid_data(tampered(|request| request.signature_type = 1)));
+    assert!(is_invalid_data(tampered(|request| request.signature_length = 512)));
+    assert!(is_invalid_data(tampered(|request| request.owner = base64_url::encode(&[7; 32]))));
+    assert!(is_invalid_data(tampered(|request| request.signature_data = base64_url::encode(&[0; 48]))));
+    assert!(is_invalid_data(tampered(|request| request.unsigned_item = "!!".to_string())));
+    // the carried item changed after the message was exported
+    assert!(is_invalid_data(tampered(|request| {
+        let mut binary = base64_url::decode(&request.unsigned_item).unwrap();
+        let last = binary.len() - 1;
+        binary[last] ^= 1;
+        request.unsigned_item = base64_url::encode(&binary);
+    })));
+}
+
+#[test]
+fn a_signature_from_another_key_is_rejected() {
+    let original = request(&ed25519_signer());
+    let signature = sign_externally(&Ed25519Signer::new(&[8; 32]).unwrap(), &original.to_json().unwrap());
+    assert!(matches!(apply_signing_request(&original, &signature), Err(ArBundleErrors::InvalidSignature)));
+}
+
+#[test]
+fn a_signature_of_the_wrong_length_is_rejected() {
+    let signer = ed25519_signer();
+    let original = request(&signer);
+    let mut signature = sign_externally(&signer, &original.to_json().unwrap());
+    signature.push(0);
+    let result = apply_signing_request(&original, &signature);
+    assert!(matches!(result, Err(ArBundleErrors::IoFailure(e)) if e.kind() == ErrorKind::InvalidInput));
+}
@@ -0,0 +1,58 @@
+use std::fs;
+use ar_bundles::{
+    ar_data_bundle::sign,
+    ar_data_create::{create_data, Data},
+    constants::SignatureConfig,
+    data_item::SignedDataItem,
+    signing::{chains::solana_signer::SolanaSigner, signer::Signer}
+};
+
+// The RFC 8032 test 1 key as a solana-keygen id.json and as a base58 secret key
+const KEYPAIR_JSON: &str = "[157,97,177,157,239,253,90,96,186,132,74,244,146,236,44,196,68,73,197,105,123,50,105,25,112,59,172,3,28,174,127,96,\
+215,90,152,1,130,177,10,183,213,75,254,211,201,100,7,58,14,225,114,243,218,166,35,37,175,2,26,104,247,7,81,26]";
+const SECRET_KEY: &str = "49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw";
+const ADDRESS: &str = "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z";
+
+#[test]
+fn keypair_json_and_base58_load_the_known_address() {
+    let from_json = SolanaSigner::from_keypair_json(KEYPAIR_JSON).unwrap();
+    let from_base58 = SolanaSigner::from_base58(SECRET_KEY).unwrap();
+    assert_eq!(from_json.address(), ADDRESS);
+    assert_eq!(from_base58.address(), ADDRESS);
+    assert_eq!(bs58::encode(from_json.get_public_key()).into_string(), ADDRESS);
+    assert_eq!(from_json.sign(b"solana").unwrap(), from_base58.sign(b"solana").unwrap());
+}
+
+#[test]
+fn keypair_files_load() {
+    let path = std::env::temp_dir().join(format!("ar-bundles-solana-{}.json", std::process::id()));
+    fs::write(&path, KEYPAIR_JSON).unwrap();
+    let signer = SolanaSigner::from_keypair_file(path.to_str().unwrap());
+    fs::remove_file(&path).unwrap();
+    assert_eq!(signer.unwrap().address(), ADDRESS);
+}
+
+#[test]
+fn malformed_keys_are_rejected() {
+    assert!(SolanaSigner::from_keypair_json("[1, 2, 3]").is_err());
+    assert!(SolanaSigner::from_keypair_json("[256]").is_err());
+    assert!(SolanaSigner::from_keypair_json("not json").is_err());
+    assert!(SolanaSigner::from_base58("0OIl").is_err());
+    assert!(SolanaSigner::from_keypair_file("/nonexistent/id.json").is_err());
+
+    let mismatched = KEYPAIR_JSON.replacen("215,", "216,", 1);
+    assert!(SolanaSigner::from_keypair_json(&mismatched).is_err());
+}
+
+#[test]
+fn signed_items_are_type_4_and_verify() {
+    let signer = SolanaSigner::from_base58(SECRET_KEY).unwrap();
+    let unsigned = create_data(Data::StringData("solana".to_string()), &signer, None).unwrap();
+    let binary = sign(unsigned, &signer).unwrap().get_raw();
+    assert_eq!(&binary[..2], &[4, 0]);
+    assert!(SignedDataItem::verify_bytes(&binary).unwrap().is_valid());
+
+    let item = SignedDataItem::from_bytes(binary).unwrap();
+    assert_eq!(item.get_signature_type().unwrap(), SignatureConfig::SOLANA);
+    assert_eq!(item.owner_address().unwrap(), ADDRESS);
+}
\ No newline at end of file
@@ -0,0 +1,160 @@
+use std::io::{Cursor, ErrorKind, Read};
+use ar_bundles::{
+    ar_data_create::{create_and_sign_streaming, create_and_sign_streaming_async, DataItemCreateOptions},
+    data_item::SignedDataItem,
+    deep_hash::{deep_hash, deep_hash_stream, deep_hash_stream_async, DeepHashChunk},
+    constants::SignatureConfig,
+    errors::ArBundleErrors,
+    signing::{chains::ed25519_signer::Ed25519Signer, signer::Signer}
+};
+use common::{ed25519_signer, tag};
+
+mod common;
+
+fn payload(length: usize) -> Vec<u8> {
+    (0..length).map(|i| (i % 251) as u8).collect()
+}
+
+fn in_memory_hash(data: &[u8]) -> [u8; 48] {
+    deep_hash(&DeepHashChunk::List(vec![
+        DeepHashChunk::Blob(b"dataitem"),
+        DeepHashChunk::Blob(b"1"),
+        DeepHashChunk::Blob(data)
+    ]))
+}
+
+fn is_io_error(result: Result<impl Sized, ArBundleErrors>, kind: ErrorKind) -> bool {
+    matches!(result, Err(ArBundleErrors::IoFailure(e)) if e.kind() == kind)
+}
+
+/// Claims ed25519's 64 byte signatures but returns one byte more, which would spill into the owner
+struct OversizedSigner(Ed25519Signer);
+
+impl Signer for OversizedSigner {
+    fn signature_type(&self) -> SignatureConfig {
+        self.0.signature_type()
+    }
+
+    fn get_signature_length(&self) -> usize {
+        self.0.get_signature_length()
+    }
+
+    fn get_owner_length(&self) -> usize {
+        self.0.get_owner_length()
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, ArBundleErrors> {
+        let mut signature = self.0.sign(message)?;
+        signature.push(0xff);
+        Ok(signature)
+    }
+
+    fn get_public_key(&self) -> Vec<u8> {
+        self.0.get_public_key()
+    }
+}
+
+fn opts() -> DataItemCreateOptions {
+    DataItemCreateOptions { target: None, anchor: None, tags: Some(vec![tag("Content-Type", "application/octet-stream")]) }
+}
+
+#[test]
+fn stream_hash_matches_in_memory_hash() {
+    let prefix = [DeepHashChunk::Blob(b"dataitem"), DeepHashChunk::Blob(b"1")];
+    for length in [0, 1000, 200 * 1024 + 7] {
+        let data = payload(length);
+        let streamed = deep_hash_stream(&prefix, &mut data.as_slice(), length as u64).unwrap();
+        assert_eq!(streamed, in_memory_hash(&data), "length {}", length);
+    }
+}
+
+#[tokio::test]
+async fn async_stream_hash_matches_in_memory_hash() {
+    let prefix = [DeepHashChunk::Blob(b"dataitem"), DeepHashChunk::Blob(b"1")];
+    for length in [0, 1000, 200 * 1024 + 7] {
+        let data = payload(length);
+        let streamed = deep_hash_stream_async(&prefix, &mut data.as_slice(), length as u64).await.unwrap();
+        assert_eq!(streamed, in_memory_hash(&data), "length {}", length);
+    }
+}
+
+#[tokio::test]
+async fn short_readers_are_unexpected_eof() {
+    let prefix = [DeepHashChunk::Blob(b"dataitem")];
+    let data = payload(100);
+    assert!(is_io_error(deep_hash_stream(&prefix, &mut data.as_slice(), 101), ErrorKind::UnexpectedEof));
+    assert!(is_io_error(deep_hash_stream_async(&prefix, &mut data.as_slice(), 101).await, ErrorKind::UnexpectedEof));
+
+    let mut output = Cursor::new(Vec::new());
+    let result = create_and_sign_streaming(&mut data.as_slice(), 101, &ed25519_signer(), None, &mut output);
+    assert!(is_io_error(result, ErrorKind::UnexpectedEof));
+}
+
+#[test]
+fn streamed_items_parse_and_verify() {
+    for length in [0, 1000, 200 * 1024 + 7] {
+        let data = payload(length);
+        let mut output = Cursor::new(Vec::new());
+        let id = create_and_sign_streaming(&mut data.as_slice(), length as u64, &ed25519_signer(), Some(&opts()), &mut output).unwrap();
+        assert_eq!(output.position(), output.get_ref().len() as u64);
+
+        let binary = output.into_inner();
+        assert!(SignedDataItem::verify_bytes(&binary).unwrap().is_valid());
+        let item = SignedDataItem::from_bytes(binary).unwrap();
+        assert_eq!(item.id(), id);
+        assert_eq!(item.raw_data(), data.as_slice());
+        assert_eq!(item.tags(), &[tag("Content-Type", "application/octet-stream")]);
+    }
+}
+
+#[tokio::test]
+async fn async_streamed_items_parse_and_verify() {
+    for length in [0, 1000, 200 * 1024 + 7] {
+        let data = payload(length);
+        let mut output = Cursor::new(Vec::new());
+        let id = create_and_sign_streaming_async(&mut data.as_slice(), length as u64, &ed25519_signer(), Some(&opts()), &mut output)
+            .await
+            .unwrap();
+
+        let binary = output.into_inner();
+        assert!(SignedDataItem::verify_bytes(&binary).unwrap().is_valid());
+        let item = SignedDataItem::from_bytes(binary).unwrap();
+        assert_eq!(item.id(), id);
+        assert_eq!(item.raw_data(), data.as_slice());
+    }
+}
+
+#[tokio::test]
+async fn readers_longer_than_the_data_length_are_rejected() {
+    let data = payload(100);
+    let mut output = Cursor::new(Vec::new());
+    let result = create_and_sign_streaming(&mut data.as_slice(), 99, &ed25519_signer(), None, &mut output);
+    assert!(is_io_error(result, ErrorKind::InvalidInput));
+
+    let mut output = Cursor::new(Vec::new());
+    let result = create_and_sign_streaming_async(&mut data.as_slice(), 99, &ed25519_signer(), None, &mut output).await;
+    assert!(is_io_error(result, ErrorKind::InvalidInput));
+
+    let mut output = Cursor::new(Vec::new());
+    let id = create_and_sign_streaming(&mut data.as_slice().take(99), 99, &ed25519_signer(), None, &mut output).unwrap();
+    let item = SignedDataItem::from_bytes(output.into_inner()).unwrap();
+    assert_eq!(item.id(), id);
+    assert_eq!(item.raw_data(), &data[..99]);
+}
+
+#[tokio::test]
+async fn signatures_of_the_wrong_length_are_rejected() {
+    let data = payload(100);
+    let signer = OversizedSigner(ed25519_signer());
+    let mut output = Cursor::new(Vec::new());
+    let result = create_and_sign_streaming(&mut data.as_slice(), 100, &signer, None, &mut output);
+    assert!(is_io_error(result, ErrorKind::InvalidInput));
+    // nothing was written into the signature slot or past it
+    assert!(output.get_ref()[2..66].iter().all(|byte| *byte == 0));
+    assert_eq!(&output.get_ref()[66..98], signer.get_public_key().as_slice());
+
+    let mut output = Cursor::new(Vec::new());
+    let result = create_and_sign_streaming_async(&mut data.as_slice(), 100, &signer, None, &mut output).await;
+    assert!(is_io_error(result, ErrorKind::InvalidInput));
+    assert_eq!(&output.get_ref()[66..98], signer.get_public_key().as_slice());
+}
@@ -0,0 +1,102 @@
+use ar_bundles::{
+    ar_data_create::DataItemBuilder,
+    data_item::SignedDataItem,
+    errors::ArBundleErrors,
+    tags::{deserialize_tags, serialize_tags, Tag}
+};
+use common::{ed25519_signer, tag};
+
+mod common;
+
+fn avro_string(s: &str) -> Vec<u8> {
+    // only valid for strings shorter than 64 bytes, whose zigzag length fits in one byte
+    let mut bytes = vec![(s.len() * 2) as u8];
+    bytes.extend_from_slice(s.as_bytes());
+    bytes
+}
+
+#[test]
+fn matches_avsc_output() {
+    // avsc: tagsParser.toBuffer([{ name: "Content-Type", value: "text/plain" }])
+    let mut expected = vec![0x02];
+    expected.extend(avro_string("Content-Type"));
+    expected.extend(avro_string("text/plain"));
+    expected.push(0x00);
+    assert_eq!(serialize_tags(&[tag("Content-Type", "text/plain")]).unwrap(), expected);
+
+    assert_eq!(serialize_tags(&[]).unwrap(), vec![0x00]);
+}
+
+#[test]
+fn multi_byte_varints() {
+    let value = "v".repeat(100);
+    let encoded = serialize_tags(&[tag("n", &value)]).unwrap();
+    // 100 zigzags to 200, which needs two varint bytes
+    assert_eq!(&encoded[..5], &[0x02, 0x02, b'n', 0xc8, 0x01]);
+    assert_eq!(encoded.len(), 5 + 100 + 1);
+
+    let tags: Vec<Tag> = (0..64).map(|i| tag("i", &i.to_string())).collect();
+    let encoded = serialize_tags(&tags).unwrap();
+    assert_eq!(&encoded[..2], &[0x80, 0x01]);
+    assert_eq!(deserialize_tags(encoded).unwrap(), tags);
+}
+
+#[test]
+fn round_trips() {
+    let cases = vec![
+        vec![tag("Content-Type", "application/json")],
+        vec![tag("App-Name", "ar-bundles"), tag("App-Version", "0.1.0"), tag("héllo", "wörld ✓ 🎉")],
+        vec![tag(&"k".repeat(1024), &"v".repeat(2048))],
+        vec![tag("", "")]
+    ];
+    for tags in cases {
+        let encoded = serialize_tags(&tags).unwrap();
+        assert_eq!(deserialize_tags(encoded.clone()).unwrap(), tags);
+        let decoded = deserialize_tags(encoded.clone()).unwrap();
+        assert_eq!(serialize_tags(&decoded).unwrap(), encoded);
+    }
+}
+
+#[test]
+fn reads_negative_count_blocks() {
+    // a block of 2 items written with its byte size, then a plain block of 1, then the terminator
+    let mut items = avro_string("a");
+    items.extend(avro_string("1"));
+    items.extend(avro_string("b"));
+    items.extend(avro_string("2"));
+    let mut encoded = vec![0x03, (items.len() * 2) as u8];
+    encoded.extend(items);
+    encoded.push(0x02);
+    encoded.extend(avro_string("c"));
+    encoded.extend(avro_string("3"));
+    encoded.push(0x00);
+
+    assert_eq!(deserialize_tags(encoded).unwrap(), vec![tag("a", "1"), tag("b", "2"), tag("c", "3")]);
+}
+
+#[test]
+fn rejects_bytes_after_the_array() {
+    assert!(matches!(deserialize_tags(vec![0x00, 0x00]), Err(ArBundleErrors::TrailingBytes { offset: 1 })));
+}
+
+#[test]
+fn rejects_missing_fields() {
+    let missing = Tag { name: Some("a".to_string()), value: None };
+    assert!(matches!(serialize_tags(&[missing]), Err(ArBundleErrors::TagIsUndefinedOrEmpty)));
+}
+
+#[test]
+fn tagged_items_sign_and_verify() {
+    let signer = ed25519_signer();
+    let item = DataItemBuilder::new()
+        .data_from_bytes(b"tagged".to_vec())
+        .tag("Content-Type", "text/plain")
+        .tag("App-Name", "ar-bundles")
+        .sign(&signer)
+        .unwrap();
+
+    let report = SignedDataItem::verify_bytes(&item.get_raw()).unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.tag_count, 2);
+    assert_eq!(item.tags(), &[tag("Content-Type", "text/plain"), tag("App-Name", "ar-bundles")]);
+}
\ No newline at end of file
@@ -0,0 +1,127 @@
+use ar_bundles::{
+    ar_data_bundle::{bundle, sign},
+    ar_data_create::{create_data, create_data_with_policy, Data, DataItemBuilder, DataItemCreateOptions},
+    data_item::SignedDataItem,
+    errors::{ArBundleErrors, TagValidationError},
+    tags::{serialize_tags, Tag, TagPolicy}
+};
+use common::{ed25519_signer, raw_item, tag};
+
+mod common;
+
+fn invalid_tag(result: Result<SignedDataItem, ArBundleErrors>) -> TagValidationError {
+    match result {
+        Err(ArBundleErrors::InvalidTag(e)) => e,
+        other => panic!("expected a tag validation error, got {:?}", other.map(|item| item.id()))
+    }
+}
+
+#[test]
+fn ans104_limits_are_accepted() {
+    let many = vec![tag("n", "v"); 128];
+    let long_name = vec![tag(&"n".repeat(1024), "v")];
+    let long_value = vec![tag("n", &"v".repeat(3072))];
+    for tags in [many, long_name, long_value] {
+        let opts = DataItemCreateOptions { target: None, anchor: None, tags: Some(tags) };
+        let item = create_data(Data::BinaryData(b"data".to_vec()), &ed25519_signer(), Some(&opts)).unwrap();
+        let item = sign(item, &ed25519_signer()).unwrap();
+        assert!(SignedDataItem::verify_bytes(&item.get_raw()).unwrap().is_valid());
+    }
+}
+
+#[test]
+fn creation_rejects_tags_outside_the_limits() {
+    let cases = vec![
+        (vec![tag("n", "v"); 129], TagValidationError::TooManyTags { count: 129, max: 128 }),
+        (vec![tag("a", "1"), tag("", "2")], TagValidationError::EmptyName { index: 1 }),
+        (vec![tag("a", "")], TagValidationError::EmptyValue { index: 0 }),
+        (vec![tag(&"n".repeat(1025), "v")], TagValidationError::NameTooLong { index: 0, length: 1025, max: 1024 }),
+        (vec![tag("n", &"v".repeat(3073))], TagValidationError::ValueTooLong { index: 0, length: 3073, max: 3072 }),
+        (vec![Tag { name: None, value: Some("v".to_string()) }], TagValidationError::MissingName { index: 0 }),
+        (vec![Tag { name: Some("n".to_string()), value: None }], TagValidationError::MissingValue { index: 0 }),
+        (vec![tag(&"n".repeat(1024), &"v".repeat(3072))], TagValidationError::TooManyBytes { length: 4102, max: 4096 })
+    ];
+    for (tags, expected) in cases {
+        let opts = DataItemCreateOptions { target: None, anchor: None, tags: Some(tags) };
+        match create_data(Data::BinaryData(vec![]), &ed25519_signer(), Some(&opts)) {
+            Err(ArBundleErrors::InvalidTag(e)) => assert_eq!(e, expected),
+            _ => panic!("expected {:?}", expected)
+        }
+    }
+}
+
+#[test]
+fn parsing_rejects_tags_outside_the_limits() {
+    let too_many = vec![tag("n", "v"); 129];
+    let item = raw_item(&[0], &[0], 129, &serialize_tags(&too_many).unwrap(), &[]);
+    assert_eq!(invalid_tag(SignedDataItem::from_bytes(item)), TagValidationError::TooManyTags { count: 129, max: 128 });
+
+    let item = raw_item(&[0], &[0], 1, &serialize_tags(&[tag("", "v")]).unwrap(), &[]);
+    assert_eq!(invalid_tag(SignedDataItem::from_bytes(item)), TagValidationError::EmptyName { index: 0 });
+
+    let item = raw_item(&[0], &[0], 2, &[4, 2, b'a', 2, b'1', 2, b'b', 2, 0xc3, 0], &[]);
+    assert_eq!(invalid_tag(SignedDataItem::from_bytes(item)), TagValidationError::InvalidUtf8 { index: 1 });
+}
+
+#[test]
+fn stricter_policies_are_applied() {
+    let policy = TagPolicy { max_tags: 2, max_name_bytes: 16, max_value_bytes: 8, max_tag_bytes: 64 };
+    let built = DataItemBuilder::new()
+        .tag("Content-Type", "application/json")
+        .tag_policy(policy)
+        .sign(&ed25519_signer());
+    assert!(matches!(
+        built,
+        Err(ArBundleErrors::InvalidTag(TagValidationError::ValueTooLong { index: 0, length: 16, max: 8 }))
+    ));
+
+    let item = DataItemBuilder::new().tag("a", "1").tag("b", "2").tag("c", "3").sign(&ed25519_signer()).unwrap();
+    assert_eq!(
+        invalid_tag(SignedDataItem::from_bytes_with_policy(item.get_raw(), &policy)),
+        TagValidationError::TooManyTags { count: 3, max: 2 }
+    );
+    assert!(SignedDataItem::from_bytes(item.get_raw()).is_ok());
+
+    assert!(matches!(
+        SignedDataItem::verify_bytes_with_policy(&item.get_raw(), &policy),
+        Err(ArBundleErrors::InvalidTag(TagValidationError::TooManyTags { count: 3, max: 2 }))
+    ));
+    let bundle = bundle(vec![item]).unwrap();
+    assert!(bundle.items().is_ok());
+    assert!(matches!(
+        bundle.items_with_policy(&policy),
+        Err(ArBundleErrors::InvalidTag(TagValidationError::TooManyTags { .. }))
+    ));
+    assert!(bundle.get_by_index_with_policy(0, &policy).is_err());
+    assert!(bundle.get_by_id_with_policy(&bundle.get_ids()[0], &policy).is_err());
+
+    let opts = DataItemCreateOptions { target: None, anchor: None, tags: Some(vec![tag("a", "1"); 3]) };
+    assert!(create_data(Data::BinaryData(vec![]), &ed25519_signer(), Some(&opts)).is_ok());
+    assert!(matches!(
+        create_data_with_policy(Data::BinaryData(vec![]), &ed25519_signer(), Some(&opts), &policy),
+        Err(ArBundleErrors::InvalidTag(TagValidationError::TooManyTags { count: 3, max: 2 }))
+    ));
+}
+
+#[test]
+fn total_tag_bytes_are_limited() {
+    let policy = TagPolicy { max_tag_bytes: 16, ..TagPolicy::default() };
+    let built = DataItemBuilder::new().tag("Content-Type", "text/plain").tag_policy(policy).sign(&ed25519_signer());
+    assert!(matches!(
+        built,
+        Err(ArBundleErrors::InvalidTag(TagValidationError::TooManyBytes { length: 26, max: 16 }))
+    ));
+
+    let item = DataItemBuilder::new().tag("Content-Type", "text/plain").sign(&ed25519_signer()).unwrap();
+    assert_eq!(item.view().tags().len(), 26);
+}
+
+#[test]
+fn looser_policies_are_capped_at_ans104() {
+    let policy = TagPolicy { max_tags: 1000, max_name_bytes: 5000, max_value_bytes: 5000, max_tag_bytes: 10000 };
+    let built = DataItemBuilder::new().tag(&"n".repeat(1025), "v").tag_policy(policy).sign(&ed25519_signer());
+    assert!(matches!(
+        built,
+        Err(ArBundleErrors::InvalidTag(TagValidationError::NameTooLong { max: 1024, .. }))
+    ));
+}
\ No newline at end of file
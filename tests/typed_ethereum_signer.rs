@@ -0,0 +1,63 @@
+use ar_bundles::{
+    ar_data_base::get_signature_data,
+    ar_data_bundle::{apply_signature, sign},
+    ar_data_create::{create_data, Data},
+    constants::SignatureConfig,
+    data_item::SignedDataItem,
+    errors::ArBundleErrors,
+    signing::{chains::typed_ethereum_signer::TypedEthereumSigner, constants::get_index_to_type, signer::Signer}
+};
+
+// Hardhat's first dev account signing the bytes 0x00..0x2f as arbundles' EIP-712 typed data:
+// domain { name: "Bundlr", version: "1" }, Bundlr(bytes Transaction hash,address address)
+const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+const ADDRESS: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+const SIGNATURE: &str = concat!(
+    "9dd52cfc0990964fcf946265ec9e3e10784f6a18c905bcf2bdbfdc5165145123",
+    "7ca954512f7b0ea4fd8ec34dd30d9b94019fb69aaf2fa9d39a9fc7e61dbb6374",
+    "1b"
+);
+
+fn message() -> Vec<u8> {
+    (0u8..48).collect()
+}
+
+fn verify(pk: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    get_index_to_type()[&SignatureConfig::TYPEDETHEREUM](pk, message, signature)
+}
+
+#[test]
+fn matches_the_known_vector() {
+    let signer = TypedEthereumSigner::from_hex(PRIVATE_KEY).unwrap();
+    assert_eq!(signer.address(), ADDRESS);
+    assert_eq!(signer.get_public_key(), ADDRESS.as_bytes());
+    assert_eq!(hex::encode(signer.sign(&message()).unwrap()), SIGNATURE);
+    assert!(verify(ADDRESS.as_bytes(), &message(), &hex::decode(SIGNATURE).unwrap()));
+}
+
+#[test]
+fn rejects_a_wrong_address() {
+    let signature = hex::decode(SIGNATURE).unwrap();
+    // Hardhat's second dev account
+    assert!(!verify(b"0x70997970C51812dc3A010C7d01b50e0d17dc79C8", &message(), &signature));
+    assert!(!verify(b"not an address", &message(), &signature));
+    assert!(!verify(ADDRESS.as_bytes(), b"another message", &signature));
+}
+
+#[test]
+fn signed_items_verify_and_report_the_address() {
+    let signer = TypedEthereumSigner::from_hex(PRIVATE_KEY).unwrap();
+    let unsigned = create_data(Data::StringData("typed".to_string()), &signer, None).unwrap();
+    let binary = sign(unsigned, &signer).unwrap().get_raw();
+    assert_eq!(&binary[..2], &[7, 0]);
+    assert!(SignedDataItem::verify_bytes(&binary).unwrap().is_valid());
+
+    let item = SignedDataItem::from_bytes(binary).unwrap();
+    assert_eq!(item.get_signature_type().unwrap(), SignatureConfig::TYPEDETHEREUM);
+    assert_eq!(item.owner_address().unwrap(), ADDRESS);
+
+    let other = TypedEthereumSigner::new(&[7; 32]).unwrap();
+    let unsigned = create_data(Data::StringData("typed".to_string()), &signer, None).unwrap();
+    let foreign = other.sign(&get_signature_data(&unsigned)).unwrap();
+    assert!(matches!(apply_signature(unsigned, &foreign), Err(ArBundleErrors::InvalidSignature)));
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use std::str::FromStr;
+use ar_bundles::{
+    errors::ArBundleErrors,
+    types::{Anchor, ArweaveAddress, TxId}
+};
+
+// id of the signed fixture item in tests/deep_hash.rs, it has both url safe characters
+const TX_ID: &str = "LrXr1wlyCwy-E3GeIzL4ZOnJM9m0IWxXTlPW_YcS76A";
+
+fn is_invalid_input<T>(result: Result<T, ArBundleErrors>) -> bool {
+    matches!(result, Err(ArBundleErrors::IoFailure(e)) if e.kind() == std::io::ErrorKind::InvalidInput)
+}
+
+#[test]
+fn real_txids_parse_and_display_unchanged() {
+    let id = TxId::from_str(TX_ID).unwrap();
+    assert_eq!(id.as_bytes(), base64_url::decode(TX_ID).unwrap().as_slice());
+    assert_eq!(id.to_string(), TX_ID);
+    assert_eq!(id.to_string().len(), 43);
+    assert_eq!(TX_ID.parse::<ArweaveAddress>().unwrap().to_string(), TX_ID);
+    assert_eq!(TX_ID.parse::<Anchor>().unwrap().as_bytes(), id.as_bytes());
+}
+
+#[test]
+fn wrong_lengths_are_rejected() {
+    assert!(is_invalid_input(TxId::from_str(&TX_ID[..42])));
+    assert!(is_invalid_input(TxId::from_str(&TX_ID[..40])));
+    assert!(is_invalid_input(TxId::from_str(&format!("{}AAAA", TX_ID))));
+    assert!(is_invalid_input(TxId::from_str("")));
+    assert!(is_invalid_input(TxId::try_from([0u8; 31].as_slice())));
+    assert!(is_invalid_input(Anchor::try_from([0u8; 33].as_slice())));
+    assert!(TxId::try_from([0u8; 32].as_slice()).is_ok());
+}
+
+#[test]
+fn non_base64url_input_is_rejected() {
+    for input in ["LrXr1wlyCwy!E3GeIzL4ZOnJM9m0IWxXTlPW_YcS76A", "LrXr1wlyCwy E3GeIzL4ZOnJM9m0IWxXTlPW_YcS76A", "not an id"] {
+        assert!(is_invalid_input(TxId::from_str(input)), "{}", input);
+        assert!(is_invalid_input(ArweaveAddress::from_str(input)), "{}", input);
+    }
+}
+
+#[test]
+fn serde_round_trips_as_a_string() {
+    let id = TxId::from_str(TX_ID).unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, format!("\"{}\"", TX_ID));
+    assert_eq!(serde_json::from_str::<TxId>(&json).unwrap(), id);
+
+    let anchor = Anchor::new([7; 32]);
+    assert_eq!(serde_json::from_str::<Anchor>(&serde_json::to_string(&anchor).unwrap()).unwrap(), anchor);
+
+    assert!(serde_json::from_str::<TxId>("\"too short\"").is_err());
+    assert!(serde_json::from_str::<TxId>("42").is_err());
+}
+
+#[test]
+fn display_round_trips_through_from_str() {
+    for bytes in [[0u8; 32], [0xff; 32], std::array::from_fn(|i| i as u8)] {
+        let address = ArweaveAddress::new(bytes);
+        assert_eq!(address.to_string().parse::<ArweaveAddress>().unwrap(), address);
+        assert_eq!(TxId::from(bytes).to_string().len(), 43);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use ar_bundles::{
+    ar_data_bundle::sign,
+    ar_data_create::{create_data, Data, DataItemCreateOptions},
+    bundle_item::BundleItemFn,
+    constants::SignatureConfig,
+    data_item::SignedDataItem,
+    signing::signer::Signer
+};
+use common::{arweave_signer, ed25519_signer, tag};
+
+mod common;
+
+fn signed<T: Signer>(signer: &T) -> Vec<u8> {
+    let opts = DataItemCreateOptions { target: None, anchor: None, tags: Some(vec![tag("Content-Type", "text/plain")]) };
+    let unsigned = create_data(Data::StringData("verify me".to_string()), signer, Some(&opts)).unwrap();
+    sign(unsigned, signer).unwrap().get_raw()
+}
+
+#[test]
+fn valid_items_report_a_valid_signature() {
+    let binary = signed(&ed25519_signer());
+    let report = SignedDataItem::verify_bytes(&binary).unwrap();
+    assert!(report.signature_valid);
+    assert!(report.is_valid());
+    assert_eq!(report.signature_type, SignatureConfig::ED25519);
+    assert_eq!(report.owner, base64_url::encode(&ed25519_signer().get_public_key()));
+    assert_eq!(report.tag_count, 1);
+    assert_eq!(report.data_size, 9);
+    assert_eq!(report.id, SignedDataItem::from_bytes(binary.clone()).unwrap().id());
+
+    let binary = signed(&arweave_signer());
+    let report = SignedDataItem::verify_bytes(&binary).unwrap();
+    assert!(report.signature_valid);
+    assert_eq!(report.signature_type, SignatureConfig::ARWEAVE);
+}
+
+#[test]
+fn a_flipped_signature_byte_is_reported_not_returned_as_an_error() {
+    for binary in [signed(&ed25519_signer()), signed(&arweave_signer())] {
+        let mut tampered = binary.clone();
+        tampered[2] ^= 1;
+        let report = SignedDataItem::verify_bytes(&tampered).unwrap();
+        assert!(!report.signature_valid);
+        assert!(!report.is_valid());
+        // the id follows the signature bytes, so it is the tampered item's id
+        assert_eq!(report.id, SignedDataItem::from_bytes(tampered.clone()).unwrap().id());
+
+        let item = SignedDataItem::from_bytes(binary.clone()).unwrap();
+        assert!(item.verify(&binary));
+        assert!(!item.verify(&tampered));
+    }
+}
+
+#[test]
+fn a_flipped_data_byte_invalidates_the_signature() {
+    let mut binary = signed(&ed25519_signer());
+    let last = binary.len() - 1;
+    binary[last] ^= 1;
+    assert!(!SignedDataItem::verify_bytes(&binary).unwrap().signature_valid);
+}
\ No newline at end of file